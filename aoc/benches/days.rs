@@ -0,0 +1,80 @@
+use common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+// The contents of a day's input file and what it parses to.
+fn read<S: Solution>(input: &str) -> (String, S::Input) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(input);
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e));
+    let parsed = S::parse(&contents).unwrap_or_else(|e| panic!("{}", e.in_file(input)));
+
+    (contents, parsed)
+}
+
+// Measures parsing and both parts separately, each against the day's own
+// input.txt. Save a baseline before a redesign and compare against it after:
+//
+//     cargo bench -p aoc -- --save-baseline before
+//     cargo bench -p aoc -- --baseline before
+fn bench<S: Solution>(c: &mut Criterion, number: u8, input: &str, parts: &[u8]) {
+    let (contents, parsed) = read::<S>(input);
+
+    let mut group = c.benchmark_group(format!("day{:02}", number));
+    // Some parts take seconds, the minimum number of samples keeps the suite bearable
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&contents))));
+
+    if parts.contains(&1) {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    }
+
+    if parts.contains(&2) {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+// A part that takes many seconds per run, measured in a group of its own that
+// runs it the same number of times for every sample instead of ramping up.
+fn bench_slow_part<S: Solution>(c: &mut Criterion, number: u8, input: &str, part: u8) {
+    let (_, parsed) = read::<S>(input);
+
+    let mut group = c.benchmark_group(format!("day{:02}-slow", number));
+    group.sampling_mode(SamplingMode::Flat);
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(180));
+
+    if part == 1 {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    } else {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench::<day01_improved::Day01>(c, 1, "day01-improved/input.txt", &[1, 2]);
+    bench::<day02::Day02>(c, 2, "day02/input.txt", &[1, 2]);
+    bench::<day03::Day03>(c, 3, "day03/input.txt", &[1, 2]);
+    bench::<day04::Day04>(c, 4, "day04/input.txt", &[1, 2]);
+    bench::<day05::Day05>(c, 5, "day05/input.txt", &[1, 2]);
+    bench::<day06::Day06>(c, 6, "day06/input.txt", &[1, 2]);
+    bench::<day07::Day07>(c, 7, "day07/input.txt", &[1, 2]);
+    bench::<day08::Day08>(c, 8, "day08/input.txt", &[1, 2]);
+    bench::<day09::Day09>(c, 9, "day09/input.txt", &[1, 2]);
+    bench::<day10::Day10>(c, 10, "day10/input.txt", &[1, 2]);
+    // Part 2 brute forces every square of every size and takes about 12s per run
+    bench::<day11::Day11>(c, 11, "day11/input.txt", &[1]);
+    bench_slow_part::<day11::Day11>(c, 11, "day11/input.txt", 2);
+    bench::<day12::Day12>(c, 12, "day12/input.txt", &[1, 2]);
+    bench::<day13::Day13>(c, 13, "day13/input.txt", &[1, 2]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+use std::collections::BTreeMap;
+
+// Known answers keyed by day and part, read from a file with one
+// `<day> <part> <answer>` per line. Multi-line answers (like the day 10
+// message) have their newlines written as `\n`.
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\n', "\\n")
+}
+
+pub fn unescape(answer: &str) -> String {
+    answer.replace("\\n", "\n")
+}
+
+pub fn parse(contents: &str) -> Result<Answers, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next().and_then(|d| d.parse().ok());
+            let part = fields.next().and_then(|p| p.parse().ok());
+
+            match (day, part, fields.next()) {
+                (Some(day), Some(part), Some(answer)) => Ok(((day, part), unescape(answer))),
+                _ => Err(format!("line {}: expected `<day> <part> <answer>`, got `{}`", i + 1, line)),
+            }
+        })
+        .collect()
+}
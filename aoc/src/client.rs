@@ -0,0 +1,173 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::answers;
+use crate::days::workspace;
+
+const YEAR: u16 = 2018;
+const USER_AGENT: &str = "advent-of-code-2018 runner by frank@naa.li";
+
+// What the puzzle site thought of a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    // Answers can only be submitted once a minute or so.
+    TooSoon,
+    AlreadySolved,
+    Unknown,
+}
+impl Verdict {
+    fn of(message: &str) -> Verdict {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    // Whether submitting the same answer again would always get the same verdict.
+    fn is_final(&self) -> bool {
+        *self == Verdict::Correct || *self == Verdict::Wrong
+    }
+}
+
+// Downloads inputs and submits answers. Everything the site answered is cached
+// on disk, so each input is only downloaded once and an answer is never
+// submitted twice.
+//
+// Configured by environment variables:
+// - `AOC_SESSION`: the session cookie of a logged in browser, or else the
+//   contents of the `.session` file at the workspace root
+// - `AOC_URL`: the site, e.g. a local stub server in tests
+// - `AOC_CACHE`: where to cache, `.aoc-cache` at the workspace root by default
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache: PathBuf,
+}
+impl Client {
+    pub fn from_env() -> Client {
+        let session = env::var("AOC_SESSION").ok()
+            .or_else(|| fs::read_to_string(workspace(".session")).ok())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+
+        Client {
+            base_url: env::var("AOC_URL").unwrap_or_else(|_| "https://adventofcode.com".to_string())
+                .trim_end_matches('/').to_string(),
+            session,
+            cache: PathBuf::from(env::var("AOC_CACHE").unwrap_or_else(|_| workspace(".aoc-cache"))),
+        }
+    }
+
+    fn day_cache(&self, day: u8) -> PathBuf {
+        self.cache.join(YEAR.to_string()).join(format!("day{:02}", day))
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref()
+            .ok_or_else(|| "No session, set AOC_SESSION or write it to .session".to_string())
+    }
+
+    fn request(&self, method: &str, path: &str) -> Result<ureq::Request, String> {
+        Ok(ureq::request(method, &format!("{}/{}/day/{}", self.base_url, YEAR, path))
+            .set("Cookie", &format!("session={}", self.session()?))
+            .set("User-Agent", USER_AGENT))
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let cached = self.day_cache(day).join("input.txt");
+
+        if let Ok(input) = fs::read_to_string(&cached) {
+            return Ok(input);
+        }
+
+        let input = body(self.request("GET", &format!("{}/input", day))?.call())
+            .map_err(|e| format!("Could not download the input of day {}: {}", day, e))?;
+
+        write(&cached, &input)?;
+        Ok(input)
+    }
+
+    // The verdict and the message the site gave for the answer.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<(Verdict, String), String> {
+        let cached = self.day_cache(day).join(format!("part{}.txt", part));
+        let mut submitted = fs::read_to_string(&cached).unwrap_or_default();
+
+        let previous = submitted.lines()
+            .filter_map(|line| line.split_once('\t'))
+            .find(|(a, _)| answers::unescape(a) == answer);
+        if let Some((_, message)) = previous {
+            return Ok((Verdict::of(message), message.to_string()));
+        }
+
+        let request = self.request("POST", &format!("{}/answer", day))?;
+        let html = body(request.send_form(&[("level", &part.to_string()), ("answer", answer)]))
+            .map_err(|e| format!("Could not submit the answer to day {} part {}: {}", day, part, e))?;
+        let message = article_text(&html);
+        let verdict = Verdict::of(&message);
+
+        if verdict.is_final() {
+            submitted.push_str(&format!("{}\t{}\n", answers::escape(answer), message));
+            write(&cached, &submitted)?;
+        }
+
+        Ok((verdict, message))
+    }
+}
+
+// The body of a successful response, or what went wrong.
+fn body(response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    response.map_err(|e| e.to_string())?
+        .into_string()
+        .map_err(|e| e.to_string())
+}
+
+fn write(path: &PathBuf, contents: &str) -> Result<(), String> {
+    path.parent().map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+// The text of the page's `<article>` on one line, without any tags.
+fn article_text(html: &str) -> String {
+    let article = html.find("<article")
+        .and_then(|start| html[start..].find("</article>").map(|end| &html[start..start + end]))
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let html = "<main>\n<article><p>That's not the right answer; your answer is too low. <a href=\"/2018/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+        let message = article_text(html);
+
+        assert_eq!(message, "That's not the right answer; your answer is too low. [Return to Day 1]");
+        assert_eq!(Verdict::of(&message), Verdict::Wrong);
+        assert_eq!(Verdict::of("You gave an answer too recently; you have to wait"), Verdict::TooSoon);
+    }
+}
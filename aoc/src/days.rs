@@ -0,0 +1,68 @@
+use common::{Generate, ParseError, Rng, Solution};
+use std::path::Path;
+
+// A file of the workspace, wherever the runner is started from, the way each
+// day's own binary finds its input.txt.
+pub fn workspace(path: &str) -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    root.join(path).to_string_lossy().into_owned()
+}
+
+pub struct Day {
+    pub number: u8,
+    pub input: String,
+    // The day's source, which starts with the puzzle description.
+    pub source: &'static str,
+    pub part1: fn(&str) -> Result<String, ParseError>,
+    pub part2: fn(&str) -> Result<String, ParseError>,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+fn part1<S: Solution>(input: &str) -> Result<String, ParseError> {
+    S::parse(input).map(|input| S::part1(&input).to_string())
+}
+
+fn part2<S: Solution>(input: &str) -> Result<String, ParseError> {
+    S::parse(input).map(|input| S::part2(&input).to_string())
+}
+
+fn day<S: Solution + Generate>(number: u8, input: &'static str, source: &'static str) -> Day {
+    Day { number, input: workspace(input), source, part1: part1::<S>, part2: part2::<S>, generate: S::generate }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        day::<day01_improved::Day01>(1, "day01-improved/input.txt", include_str!("../../day01-improved/src/lib.rs")),
+        day::<day02::Day02>(2, "day02/input.txt", include_str!("../../day02/src/lib.rs")),
+        day::<day03::Day03>(3, "day03/input.txt", include_str!("../../day03/src/lib.rs")),
+        day::<day04::Day04>(4, "day04/input.txt", include_str!("../../day04/src/lib.rs")),
+        day::<day05::Day05>(5, "day05/input.txt", include_str!("../../day05/src/lib.rs")),
+        day::<day06::Day06>(6, "day06/input.txt", include_str!("../../day06/src/lib.rs")),
+        day::<day07::Day07>(7, "day07/input.txt", include_str!("../../day07/src/lib.rs")),
+        day::<day08::Day08>(8, "day08/input.txt", include_str!("../../day08/src/lib.rs")),
+        day::<day09::Day09>(9, "day09/input.txt", include_str!("../../day09/src/lib.rs")),
+        day::<day10::Day10>(10, "day10/input.txt", include_str!("../../day10/src/lib.rs")),
+        day::<day11::Day11>(11, "day11/input.txt", include_str!("../../day11/src/lib.rs")),
+        day::<day12::Day12>(12, "day12/input.txt", include_str!("../../day12/src/lib.rs")),
+        day::<day13::Day13>(13, "day13/input.txt", include_str!("../../day13/src/lib.rs")),
+    ]
+}
+
+pub fn find(number: u8) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Description;
+
+    #[test]
+    fn every_day_is_described() {
+        for day in super::all() {
+            let description = Description::of(day.source).unwrap();
+
+            assert!(description.title.starts_with(&format!("Day {}: ", day.number)), "{}", description.title);
+            assert_eq!(description.parts.len(), 2, "day {}", day.number);
+        }
+    }
+}
@@ -0,0 +1,338 @@
+mod answers;
+mod client;
+mod days;
+mod output;
+mod scaffold;
+
+use common::{input_name, Description, ParseError, Rng};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+use crate::client::{Client, Verdict};
+use crate::days::Day;
+use crate::output::{Format, Record, Row};
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--input <path|->] [--format text|json|csv]
+    aoc verify [<day|all>] [--answers <path>]
+    aoc batch <day> <directory> [--format text|json|csv]
+    aoc generate <day> [--size <n>] [--seed <n>]
+    aoc new <day> [--root <path>]
+    aoc describe <day> [--part 1|2]
+    aoc download <day> [--output <path>]
+    aoc submit <day> <part> [<answer>]";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+// Positional arguments and `--name value` options of a command.
+struct Args<'a> {
+    positional: Vec<&'a str>,
+    options: Vec<(&'a str, &'a str)>,
+}
+impl<'a> Args<'a> {
+    fn parse(args: &'a [String]) -> Args<'a> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                let value = args.next().unwrap_or_else(|| fail(USAGE));
+                options.push((name, value.as_str()));
+            } else {
+                positional.push(arg.as_str());
+            }
+        }
+
+        Args { positional, options }
+    }
+
+    fn option(&self, name: &str) -> Option<&'a str> {
+        self.options.iter().find(|(n, _)| *n == name).map(|(_, value)| *value)
+    }
+
+    fn days(&self) -> Vec<Day> {
+        match self.positional.first() {
+            None | Some(&"all") => days::all(),
+            Some(number) => {
+                let day = number.parse().ok()
+                    .and_then(days::find)
+                    .unwrap_or_else(|| fail(&format!("Unknown day: {}", number)));
+                vec![day]
+            }
+        }
+    }
+}
+
+fn read_input(path: &str) -> String {
+    common::read_input(path)
+        .unwrap_or_else(|e| fail(&format!("Could not read {}: {}", input_name(path), e)))
+}
+
+fn parse_error(error: ParseError, path: &str) -> String {
+    error.in_file(input_name(path)).to_string()
+}
+
+fn solve(day: &Day, input: &str) -> Result<Vec<Record>, ParseError> {
+    [(1, day.part1), (2, day.part2)].iter().map(|&(part, solve)| {
+        let start = Instant::now();
+        let answer = solve(input)?;
+
+        Ok(Record { day: day.number, part, answer, duration: start.elapsed() })
+    }).collect()
+}
+
+fn run_day(day: &Day, path: Option<&str>) -> Vec<Record> {
+    let path = path.unwrap_or(&day.input);
+    let input = read_input(path);
+
+    solve(day, &input).unwrap_or_else(|e| fail(&parse_error(e, path)))
+}
+
+fn format(args: &Args) -> Format {
+    args.option("format").map_or(Some(Format::Text), Format::from_name)
+        .unwrap_or_else(|| fail("--format has to be one of json, csv or text"))
+}
+
+fn run(args: &Args) {
+    if args.positional.is_empty() {
+        fail(USAGE);
+    }
+
+    let days = args.days();
+    let path = args.option("input");
+    let format = format(args);
+
+    if days.len() > 1 && path.is_some() {
+        fail("--input can only be used with a single day");
+    }
+
+    let records: Vec<Record> = days.iter().flat_map(|day| run_day(day, path)).collect();
+    format.print(&records);
+}
+
+// The files of a directory sorted by name, leaving out hidden ones like `.gitkeep`.
+fn input_files(directory: &str) -> Vec<String> {
+    let entries = fs::read_dir(directory)
+        .unwrap_or_else(|e| fail(&format!("Could not read {}: {}", directory, e)));
+    let mut files = Vec::new();
+
+    for entry in entries {
+        let path = entry.unwrap_or_else(|e| fail(&format!("Could not read {}: {}", directory, e))).path();
+        let hidden = path.file_name().is_none_or(|name| name.to_string_lossy().starts_with('.'));
+
+        if path.is_file() && !hidden {
+            files.push(path.to_string_lossy().into_owned());
+        }
+    }
+
+    files.sort();
+    files
+}
+
+// Solves a day for every input in a directory. Inputs that can't be parsed are
+// reported and left out of the table, the others are still solved.
+fn batch(args: &Args) {
+    if args.positional.len() != 2 || args.positional[0] == "all" {
+        fail(USAGE);
+    }
+
+    let day = args.days().remove(0);
+    let directory = args.positional[1];
+    let format = format(args);
+    let files = input_files(directory);
+    let mut rows = Vec::new();
+    let mut failed = false;
+
+    if files.is_empty() {
+        fail(&format!("No inputs in {}", directory));
+    }
+
+    for path in files {
+        match solve(&day, &read_input(&path)) {
+            Ok(records) => rows.push(Row { input: path, records }),
+            Err(e) => {
+                failed = true;
+                eprintln!("{}", parse_error(e, &path));
+            }
+        }
+    }
+
+    format.print_batch(&rows);
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn verify(args: &Args) {
+    let path = args.option("answers").map_or_else(|| days::workspace("answers.txt"), String::from);
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|e| fail(&format!("Could not read {}: {}", path, e)));
+    let answers = answers::parse(&contents)
+        .unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for day in args.days() {
+        let input = read_input(&day.input);
+
+        for &(part, solve) in &[(1, day.part1), (2, day.part2)] {
+            let expected = match answers.get(&(day.number, part)) {
+                Some(expected) => expected,
+                None => {
+                    println!("Day {:>2} part {}: no answer recorded", day.number, part);
+                    continue;
+                }
+            };
+
+            let answer = match solve(&input) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    failed += 1;
+                    println!("Day {:>2} part {}: FAILED, {}", day.number, part, parse_error(e, &day.input));
+                    continue;
+                }
+            };
+
+            if &answer == expected {
+                passed += 1;
+                println!("Day {:>2} part {}: ok", day.number, part);
+            } else {
+                failed += 1;
+                println!("Day {:>2} part {}: FAILED, expected {} but got {}",
+                    day.number, part, answers::escape(expected), answers::escape(&answer));
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+// Prints a random input, e.g. to pipe into `aoc run <day> --input -`.
+fn generate(args: &Args) {
+    if args.positional.len() != 1 || args.positional[0] == "all" {
+        fail(USAGE);
+    }
+
+    let day = args.days().remove(0);
+    let size = args.option("size").map_or(Some(100), |size| size.parse().ok())
+        .unwrap_or_else(|| fail("--size has to be a number"));
+    let seed = args.option("seed").map_or(Some(0), |seed| seed.parse().ok())
+        .unwrap_or_else(|| fail("--seed has to be a number"));
+
+    print!("{}", (day.generate)(&mut Rng::new(seed), size));
+}
+
+fn new(args: &Args) {
+    let number = match args.positional.as_slice() {
+        [number] => number.parse().ok().filter(|number| (1..=25).contains(number))
+            .unwrap_or_else(|| fail("The day has to be between 1 and 25")),
+        _ => fail(USAGE),
+    };
+    let root = args.option("root").map_or_else(|| days::workspace(""), String::from);
+
+    for path in scaffold::new_day(Path::new(&root), number).unwrap_or_else(|e| fail(&e)) {
+        println!("Wrote {}", path.display());
+    }
+}
+
+// Prints the puzzle description of a day or only one of its parts.
+fn describe(args: &Args) {
+    if args.positional.len() != 1 || args.positional[0] == "all" {
+        fail(USAGE);
+    }
+
+    let day = args.days().remove(0);
+    let description = Description::of(day.source)
+        .unwrap_or_else(|| fail(&format!("Day {} has no description", day.number)));
+    let parts = match args.option("part") {
+        None => vec![1, 2],
+        Some("1") => vec![1],
+        Some("2") => vec![2],
+        Some(_) => fail("--part has to be 1 or 2"),
+    };
+
+    println!("--- {} ---", description.title);
+
+    for part in parts {
+        if part == 2 {
+            println!("\n--- Part Two ---");
+        }
+        println!("{}", description.parts.get(part - 1).map_or("", String::as_str));
+    }
+}
+
+// Downloads a day's input into its `input.txt` unless told otherwise.
+fn download(args: &Args) {
+    if args.positional.len() != 1 || args.positional[0] == "all" {
+        fail(USAGE);
+    }
+
+    let day = args.days().remove(0);
+    let path = args.option("output").unwrap_or(&day.input);
+    let input = Client::from_env().input(day.number).unwrap_or_else(|e| fail(&e));
+
+    fs::write(path, input).unwrap_or_else(|e| fail(&format!("Could not write {}: {}", path, e)));
+    println!("Downloaded the input of day {} to {}", day.number, path);
+}
+
+// Submits the given answer, or else the one solved from the day's `input.txt`.
+fn submit(args: &Args) {
+    if args.positional.len() < 2 || args.positional.len() > 3 || args.positional[0] == "all" {
+        fail(USAGE);
+    }
+
+    let day = args.days().remove(0);
+    let part = match args.positional[1] {
+        "1" => 1,
+        "2" => 2,
+        _ => fail("The part has to be 1 or 2"),
+    };
+    let answer = match args.positional.get(2) {
+        Some(answer) => answer.to_string(),
+        None => {
+            let solve = if part == 1 { day.part1 } else { day.part2 };
+            solve(&read_input(&day.input)).unwrap_or_else(|e| fail(&parse_error(e, &day.input)))
+        }
+    };
+
+    if answer.contains('\n') {
+        fail(&format!("Day {} part {} has to be read off the output, submit it as `aoc submit {} {} <answer>`",
+            day.number, part, day.number, part));
+    }
+
+    let (verdict, message) = Client::from_env().submit(day.number, part, &answer).unwrap_or_else(|e| fail(&e));
+    println!("{}", message);
+
+    if verdict != Verdict::Correct && verdict != Verdict::AlreadySolved {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&Args::parse(&args[1..])),
+        Some("verify") => verify(&Args::parse(&args[1..])),
+        Some("batch") => batch(&Args::parse(&args[1..])),
+        Some("generate") => generate(&Args::parse(&args[1..])),
+        Some("new") => new(&Args::parse(&args[1..])),
+        Some("describe") => describe(&Args::parse(&args[1..])),
+        Some("download") => download(&Args::parse(&args[1..])),
+        Some("submit") => submit(&Args::parse(&args[1..])),
+        _ => fail(USAGE),
+    }
+}
@@ -0,0 +1,179 @@
+use std::time::Duration;
+
+// One answer of a run and how long it took to parse the input and solve the part.
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+// The answers of a day for one of the inputs of a batch.
+pub struct Row {
+    pub input: String,
+    pub records: Vec<Record>,
+}
+
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn print(&self, records: &[Record]) {
+        match self {
+            Format::Text => print_text(records),
+            Format::Json => print_json(records),
+            Format::Csv => print_csv(records),
+        }
+    }
+
+    pub fn print_batch(&self, rows: &[Row]) {
+        match self {
+            Format::Text => print_table(rows),
+            Format::Json => print_json_rows(rows),
+            Format::Csv => print_csv_rows(rows),
+        }
+    }
+}
+
+fn print_text(records: &[Record]) {
+    for (i, record) in records.iter().enumerate() {
+        if i == 0 || records[i - 1].day != record.day {
+            println!("Day {}", record.day);
+        }
+
+        if record.answer.contains('\n') {
+            print!("Part {} ({:.2?}):\n{}", record.part, record.duration, record.answer);
+        } else {
+            println!("Part {}: {} ({:.2?})", record.part, record.answer, record.duration);
+        }
+    }
+}
+
+// One row per input with the answers and timings of both parts in columns.
+// Answers spanning several lines, like a message, are printed below their row.
+fn print_table(rows: &[Row]) {
+    let header = vec!["input".to_string(), "part 1".to_string(), "time".to_string(), "part 2".to_string(), "time".to_string()];
+    let mut table = vec![header];
+
+    for row in rows {
+        let mut cells = vec![row.input.clone()];
+        for record in &row.records {
+            let answer = if record.answer.contains('\n') { "(see below)".to_string() } else { record.answer.clone() };
+            cells.push(answer);
+            cells.push(format!("{:.2?}", record.duration));
+        }
+        table.push(cells);
+    }
+
+    let widths: Vec<usize> = (0..table[0].len())
+        .map(|column| table.iter().map(|cells| cells[column].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    for (i, cells) in table.iter().enumerate() {
+        let line: Vec<String> = cells.iter().zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+
+        if i > 0 {
+            for record in rows[i - 1].records.iter().filter(|record| record.answer.contains('\n')) {
+                print!("Part {}:\n{}", record.part, record.answer);
+            }
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+// Durations are in seconds.
+fn print_json(records: &[Record]) {
+    println!("[");
+
+    for (i, record) in records.iter().enumerate() {
+        println!(
+            "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"duration\": {:.6}}}{}",
+            record.day,
+            record.part,
+            json_string(&record.answer),
+            record.duration.as_secs_f64(),
+            if i + 1 < records.len() { "," } else { "" },
+        );
+    }
+
+    println!("]");
+}
+
+fn print_json_rows(rows: &[Row]) {
+    let records: Vec<(&str, &Record)> = rows.iter()
+        .flat_map(|row| row.records.iter().map(move |record| (row.input.as_str(), record)))
+        .collect();
+
+    println!("[");
+
+    for (i, (input, record)) in records.iter().enumerate() {
+        println!(
+            "  {{\"input\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"duration\": {:.6}}}{}",
+            json_string(input),
+            record.day,
+            record.part,
+            json_string(&record.answer),
+            record.duration.as_secs_f64(),
+            if i + 1 < records.len() { "," } else { "" },
+        );
+    }
+
+    println!("]");
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn print_csv(records: &[Record]) {
+    println!("day,part,answer,duration");
+
+    for record in records {
+        println!("{},{},{},{:.6}", record.day, record.part, csv_field(&record.answer), record.duration.as_secs_f64());
+    }
+}
+
+fn print_csv_rows(rows: &[Row]) {
+    println!("input,day,part,answer,duration");
+
+    for row in rows {
+        for record in &row.records {
+            println!("{},{},{},{},{:.6}",
+                csv_field(&row.input), record.day, record.part, csv_field(&record.answer), record.duration.as_secs_f64());
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml");
+const MAIN_RS: &str = include_str!("../templates/main.rs");
+const LIB_RS: &str = include_str!("../templates/lib.rs");
+
+fn fill(template: &str, number: u8) -> String {
+    template.replace("{{dd}}", &format!("{:02}", number)).replace("{{day}}", &number.to_string())
+}
+
+// Inserts `text` right before the first `end` that comes after `start`.
+fn insert(contents: &str, start: &str, end: &str, text: &str) -> Option<String> {
+    let start = contents.find(start)?;
+    let end = start + contents[start..].find(end)?;
+    Some(format!("{}{}{}", &contents[..end], text, &contents[end..]))
+}
+
+// The files of the workspace a new day has to be added to, where it goes in
+// them and what to add.
+fn wiring(number: u8) -> Vec<(&'static str, &'static str, &'static str, String)> {
+    let name = format!("day{:02}", number);
+    let solution = format!("{}::Day{:02}", name, number);
+
+    vec![
+        ("Cargo.toml", "members = [", "\n]", format!("\n    \"{}\",", name)),
+        ("aoc/Cargo.toml", "[dependencies]", "\n\n", format!("\n{} = {{ path = \"../{}\" }}", name, name)),
+        ("aoc/src/days.rs", "pub fn all()", "\n    ]", format!(
+            "\n        day::<{}>({}, \"{}/input.txt\", include_str!(\"../../{}/src/lib.rs\")),",
+            solution, number, name, name)),
+        ("aoc/benches/days.rs", "fn days(", "\n}", format!(
+            "\n    bench::<{}>(c, {}, \"{}/input.txt\", &[1, 2]);", solution, number, name)),
+    ]
+}
+
+// Creates the crate of a new day in the workspace at `root` from the templates
+// and wires it into the runner and the benchmarks. Nothing is written unless
+// every file can be changed. Returns the files it created or changed.
+pub fn new_day(root: &Path, number: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{:02}", number);
+    let directory = root.join(&name);
+
+    if directory.exists() {
+        return Err(format!("{} already exists", directory.display()));
+    }
+
+    let mut files = Vec::new();
+
+    for (file, start, end, text) in wiring(number) {
+        let path = root.join(file);
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        if contents.contains(text.trim()) {
+            return Err(format!("{} already has {}", path.display(), name));
+        }
+
+        let changed = insert(&contents, start, end, &text)
+            .ok_or_else(|| format!("Could not find where to add {} to {}", name, path.display()))?;
+        files.push((path, changed));
+    }
+
+    files.push((directory.join("Cargo.toml"), fill(CARGO_TOML, number)));
+    files.push((directory.join("src/main.rs"), fill(MAIN_RS, number)));
+    files.push((directory.join("src/lib.rs"), fill(LIB_RS, number)));
+    files.push((directory.join("input.txt"), String::new()));
+
+    fs::create_dir_all(directory.join("src"))
+        .map_err(|e| format!("Could not create {}: {}", directory.display(), e))?;
+
+    for (path, contents) in &files {
+        fs::write(path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
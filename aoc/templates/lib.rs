@@ -0,0 +1,48 @@
+// --- Day {{day}}: Title ---
+// Paste the description of part one here, `aoc describe {{day}}` prints it.
+//
+// --- Part Two ---
+// And the description of part two here.
+
+use common::{non_blank_lines, Generate, Maybe, ParseError, Rng, Solution};
+
+pub struct Day{{dd}};
+impl Solution for Day{{dd}} {
+    type Input = Vec<String>;
+    type Part1 = Maybe<usize>;
+    type Part2 = Maybe<usize>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(non_blank_lines(input).map(|line| line.text.trim().to_string()).collect())
+    }
+
+    // No answers until the day is solved, so the runner keeps working meanwhile
+    fn part1(_: &Vec<String>) -> Maybe<usize> {
+        Maybe(None)
+    }
+
+    fn part2(_: &Vec<String>) -> Maybe<usize> {
+        Maybe(None)
+    }
+}
+
+impl Generate for Day{{dd}} {
+    fn generate(_: &mut Rng, _: usize) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day{{dd}};
+    use common::{example, examples};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    examples! {
+        Day{{dd}},
+        // Remove the #[ignore] once the example and its answers are filled in
+        #[ignore]
+        first_example: example(SOURCE, "The first line of the example", 1) => part1: 0, part2: 0;
+    }
+}
@@ -0,0 +1,9 @@
+use common::Solution;
+use day{{dd}}::Day{{dd}};
+
+fn main() {
+    let input = common::input::<Day{{dd}}>(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+    println!("{}", Day{{dd}}::part1(&input));
+    println!("{}", Day{{dd}}::part2(&input));
+}
@@ -0,0 +1,38 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Two members' inputs for day 1 and one that can't be parsed.
+fn inputs(directory: &Path) {
+    fs::create_dir_all(directory).unwrap();
+    fs::write(directory.join("alice.txt"), "+1\n-2\n").unwrap();
+    fs::write(directory.join("bob.txt"), "+3\n+3\n+4\n-2\n-4\n").unwrap();
+    fs::write(directory.join("carol.txt"), "+1\nx\n").unwrap();
+    fs::write(directory.join(".gitkeep"), "").unwrap();
+}
+
+#[test]
+fn solves_every_input() {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("batch");
+    inputs(&directory);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["batch", "1", directory.to_str().unwrap(), "--format", "csv"])
+        .output()
+        .expect("Could not run aoc batch");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let answers: Vec<&str> = stdout.lines().skip(1)
+        .map(|line| line.rsplit_once(',').unwrap().0)
+        .collect();
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("carol.txt:2:1: expected a frequency change"), "{}", stderr);
+    assert!(stdout.starts_with("input,day,part,answer,duration\n"), "{}", stdout);
+    assert_eq!(answers, vec![
+        format!("{},1,1,-1", directory.join("alice.txt").display()),
+        format!("{},1,2,0", directory.join("alice.txt").display()),
+        format!("{},1,1,4", directory.join("bob.txt").display()),
+        format!("{},1,2,10", directory.join("bob.txt").display()),
+    ]);
+}
@@ -0,0 +1,75 @@
+mod stub;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use stub::{Stub, SESSION};
+
+fn start() -> Stub {
+    let inputs = vec![(1, "+1\n-2\n+3\n".to_string())].into_iter().collect();
+    let answers = vec![((1, 1), "2".to_string())].into_iter().collect();
+    Stub::start(inputs, answers)
+}
+
+// Every test gets its own empty cache.
+fn cache(name: &str) -> PathBuf {
+    let cache = Path::new(env!("CARGO_TARGET_TMPDIR")).join("client").join(name);
+    let _ = fs::remove_dir_all(&cache);
+    cache
+}
+
+fn aoc(stub: &Stub, cache: &Path, session: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env("AOC_URL", &stub.url)
+        .env("AOC_SESSION", session)
+        .env("AOC_CACHE", cache)
+        .output()
+        .expect("Could not run aoc")
+}
+
+#[test]
+fn downloads_each_input_once() {
+    let stub = start();
+    let cache = cache("download");
+    let output = cache.join("day01.txt");
+    let args = ["download", "1", "--output", output.to_str().unwrap()];
+
+    assert!(aoc(&stub, &cache, SESSION, &args).status.success());
+    assert!(aoc(&stub, &cache, SESSION, &args).status.success());
+
+    assert_eq!(fs::read_to_string(&output).unwrap(), "+1\n-2\n+3\n");
+    assert_eq!(stub.requests().len(), 1);
+    assert_eq!(stub.requests()[0].line, "GET /2018/day/1/input HTTP/1.1");
+}
+
+#[test]
+fn needs_a_valid_session() {
+    let stub = start();
+    let cache = cache("session");
+    let output = aoc(&stub, &cache, "expired", &["download", "1", "--output", "/dev/null"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Could not download the input of day 1"));
+    assert!(!cache.exists());
+}
+
+#[test]
+fn submits_each_answer_once() {
+    let stub = start();
+    let cache = cache("submit");
+
+    let wrong = aoc(&stub, &cache, SESSION, &["submit", "1", "1", "5"]);
+    let right = aoc(&stub, &cache, SESSION, &["submit", "1", "1", "2"]);
+    let again = aoc(&stub, &cache, SESSION, &["submit", "1", "1", "5"]);
+
+    assert!(!wrong.status.success());
+    assert!(String::from_utf8_lossy(&wrong.stdout).starts_with("That's not the right answer."));
+    assert!(right.status.success());
+    assert!(String::from_utf8_lossy(&right.stdout).starts_with("That's the right answer!"));
+    assert_eq!(wrong.stdout, again.stdout);
+
+    let bodies: Vec<String> = stub.requests().into_iter().map(|request| request.body).collect();
+    assert_eq!(bodies, vec!["level=1&answer=5", "level=1&answer=2"]);
+}
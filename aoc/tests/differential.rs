@@ -0,0 +1,6 @@
+use common::assert_same_answers;
+
+#[test]
+fn day01_improved_agrees_with_day01() {
+    assert_same_answers::<day01::Day01, day01_improved::Day01>(0..50, 200, &[1, 2]);
+}
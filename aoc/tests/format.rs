@@ -0,0 +1,39 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// Two points that line up after one second into a message of two rows,
+// which has to be escaped in JSON and quoted in CSV.
+const POINTS: &str = "position=<0, 0> velocity=<1, 0>\nposition=<2, 1> velocity=<-1, 0>\n";
+
+fn run_day10(format: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "10", "--input", "-", "--format", format])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Could not run aoc run");
+
+    child.stdin.take().unwrap().write_all(POINTS.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// Durations differ from run to run, so only what comes before them is compared.
+#[test]
+fn json_records() {
+    let output = run_day10("json");
+
+    assert!(output.starts_with("[\n  {\"day\": 10, \"part\": 1, \"answer\": \"#\\n#\\n\", \"duration\": "), "{}", output);
+    assert!(output.contains("\n  {\"day\": 10, \"part\": 2, \"answer\": \"1\", \"duration\": "), "{}", output);
+    assert!(output.ends_with("}\n]\n"), "{}", output);
+}
+
+#[test]
+fn csv_records() {
+    let output = run_day10("csv");
+
+    assert!(output.starts_with("day,part,answer,duration\n10,1,\"#\n#\n\","), "{}", output);
+    assert!(output.contains("\n10,2,1,"), "{}", output);
+}
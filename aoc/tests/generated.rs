@@ -0,0 +1,66 @@
+use common::{Generate, Rng, Solution};
+
+// Generates inputs for a few seeds, each of which has to parse. Returns them
+// parsed, so the parts that always finish can be solved.
+fn generated<S: Solution + Generate>(seeds: u64, size: usize) -> Vec<S::Input> {
+    (0..seeds).map(|seed| {
+        let input = S::generate(&mut Rng::new(seed), size);
+        S::parse(&input).unwrap_or_else(|e| panic!("seed {}: {}\n{}", seed, e, input))
+    }).collect()
+}
+
+fn solve_both<S: Solution + Generate>(seeds: u64, size: usize) {
+    for input in generated::<S>(seeds, size) {
+        S::part1(&input).to_string();
+        S::part2(&input).to_string();
+    }
+}
+
+// Part 2 of day 12 only ends for some inputs and part 2 of day 11
+// takes far too long, so only part 1 is solved for them.
+fn solve_part1<S: Solution + Generate>(seeds: u64, size: usize) {
+    for input in generated::<S>(seeds, size) {
+        S::part1(&input).to_string();
+    }
+}
+
+#[test]
+fn day01() {
+    solve_both::<day01::Day01>(20, 100);
+    solve_both::<day01_improved::Day01>(20, 100);
+}
+
+#[test]
+fn days_02_to_09() {
+    solve_both::<day02::Day02>(20, 100);
+    solve_both::<day03::Day03>(20, 100);
+    solve_both::<day04::Day04>(20, 100);
+    solve_both::<day05::Day05>(20, 100);
+    solve_both::<day06::Day06>(20, 30);
+    solve_both::<day07::Day07>(20, 26);
+    solve_both::<day08::Day08>(20, 100);
+    solve_both::<day09::Day09>(20, 100);
+}
+
+#[test]
+fn day10_message_is_eight_rows_high() {
+    for input in generated::<day10::Day10>(20, 30) {
+        assert_eq!(day10::Day10::part1(&input).to_string().lines().count(), 8);
+        assert!(day10::Day10::part2(&input) > 0);
+    }
+}
+
+#[test]
+fn day11() {
+    solve_part1::<day11::Day11>(2, 0);
+}
+
+#[test]
+fn day12() {
+    solve_part1::<day12::Day12>(20, 100);
+}
+
+#[test]
+fn day13_one_cart_survives() {
+    solve_both::<day13::Day13>(20, 10);
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const WIRED: [&str; 4] = ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs", "aoc/benches/days.rs"];
+
+// A copy of the workspace files a new day is wired into.
+fn workspace(name: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("new").join(name);
+    let _ = fs::remove_dir_all(&root);
+
+    for file in &WIRED {
+        let from = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(file);
+        fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+        fs::copy(from, root.join(file)).unwrap();
+    }
+
+    root
+}
+
+fn new(root: &Path, day: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", day, "--root", root.to_str().unwrap()])
+        .output()
+        .expect("Could not run aoc new")
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap()
+}
+
+#[test]
+fn creates_and_wires_the_day() {
+    let root = workspace("creates");
+
+    assert!(new(&root, "14").status.success());
+
+    assert!(read(&root, "Cargo.toml").contains("    \"day13\",\n    \"day14\",\n]"));
+    assert!(read(&root, "aoc/Cargo.toml").contains("day13 = { path = \"../day13\" }\nday14 = { path = \"../day14\" }\n"));
+    assert!(read(&root, "aoc/src/days.rs")
+        .contains("        day::<day14::Day14>(14, \"day14/input.txt\", include_str!(\"../../day14/src/lib.rs\")),\n    ]"));
+    assert!(read(&root, "aoc/benches/days.rs").contains("    bench::<day14::Day14>(c, 14, \"day14/input.txt\", &[1, 2]);\n}"));
+
+    assert!(read(&root, "day14/Cargo.toml").contains("name = \"day14\""));
+    assert!(read(&root, "day14/src/main.rs").contains("common::input::<Day14>("));
+    assert!(read(&root, "day14/src/lib.rs").starts_with("// --- Day 14: Title ---\n"));
+    assert!(read(&root, "day14/src/lib.rs").contains("        #[ignore]\n        first_example:"));
+    assert_eq!(read(&root, "day14/input.txt"), "");
+}
+
+#[test]
+fn leaves_existing_days_alone() {
+    let root = workspace("existing");
+    let before: Vec<String> = WIRED.iter().map(|file| read(&root, file)).collect();
+
+    let output = new(&root, "13");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already has day13"));
+    assert_eq!(WIRED.iter().map(|file| read(&root, file)).collect::<Vec<_>>(), before);
+    assert!(!root.join("day13").exists());
+}
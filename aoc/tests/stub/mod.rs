@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+pub const SESSION: &str = "stub-session";
+
+// A request the stub got, e.g. `GET /2018/day/1/input`, with its form body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    pub line: String,
+    pub body: String,
+}
+
+// Stands in for the puzzle site on a local port, so the client can be tested
+// without the network. It serves `inputs` and accepts the `answers` keyed by
+// day and part, but only for the session `SESSION`.
+pub struct Stub {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+impl Stub {
+    pub fn start(inputs: HashMap<u8, String>, answers: HashMap<(u8, u8), String>) -> Stub {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                respond(stream.unwrap(), &inputs, &answers, &received);
+            }
+        });
+
+        Stub { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+// Requests are recorded before they are answered, so the client can't be done
+// before the stub knows about its request.
+fn respond(stream: TcpStream, inputs: &HashMap<u8, String>, answers: &HashMap<(u8, u8), String>, received: &Mutex<Vec<Request>>) {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    let mut headers = HashMap::new();
+    reader.read_line(&mut line).unwrap();
+
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        match header.trim_end().split_once(": ") {
+            Some((name, value)) => headers.insert(name.to_lowercase(), value.to_string()),
+            None => break,
+        };
+    }
+
+    let length = headers.get("content-length").map_or(0, |length| length.parse().unwrap());
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    let request = Request { line: line.trim_end().to_string(), body: String::from_utf8(body).unwrap() };
+
+    let path: Vec<&str> = request.line.split(' ').nth(1).unwrap().split('/').collect();
+    let day = path.get(3).and_then(|day| day.parse().ok()).unwrap_or(0);
+    let logged_in = headers.get("cookie") == Some(&format!("session={}", SESSION));
+
+    let (status, content) = match (logged_in, path.get(4)) {
+        (false, _) => ("400 Bad Request", "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_string()),
+        (true, Some(&"input")) => match inputs.get(&day) {
+            Some(input) => ("200 OK", input.clone()),
+            None => ("404 Not Found", "404 Not Found".to_string()),
+        },
+        (true, Some(&"answer")) => {
+            let form: HashMap<&str, &str> = request.body.split('&').filter_map(|field| field.split_once('=')).collect();
+            let part = form["level"].parse().unwrap();
+            let article = if answers.get(&(day, part)).map(String::as_str) == Some(form["answer"]) {
+                "That's the right answer! You are one gold star closer to fixing the time stream."
+            } else {
+                "That's not the right answer. Please wait one minute before trying again."
+            };
+            ("200 OK", format!("<html><main>\n<article><p>{}</p></article>\n</main></html>", article))
+        }
+        (true, _) => ("404 Not Found", "404 Not Found".to_string()),
+    };
+
+    let mut stream = reader.into_inner();
+    received.lock().unwrap().push(request);
+    write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, content.len(), content).unwrap();
+}
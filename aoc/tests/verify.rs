@@ -0,0 +1,16 @@
+use std::env;
+use std::process::Command;
+
+// Runs every day against its input.txt and compares with answers.txt, so a
+// refactor can't silently change a result. Started outside the workspace, as
+// the runner has to find the inputs and answers on its own.
+#[test]
+fn all_recorded_answers_still_match() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .current_dir(env::temp_dir())
+        .output()
+        .expect("Could not run aoc verify");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+}
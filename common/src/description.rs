@@ -0,0 +1,59 @@
+// The `//` comments a day's source starts with, without the comment markers.
+pub(crate) fn comments(source: &str) -> Vec<&str> {
+    source.lines()
+        .take_while(|line| line.starts_with("//") || line.trim().is_empty())
+        .map(|line| {
+            let line = line.strip_prefix("//").unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line)
+        })
+        .collect()
+}
+
+// Leaves out the blank lines around some text.
+fn trim_blank_lines(lines: &[&str]) -> String {
+    let start = lines.iter().position(|line| !line.trim().is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.trim().is_empty()).map_or(start, |end| end + 1);
+    lines[start..end].join("\n")
+}
+
+// The puzzle description at the top of a day's source, split up at its
+// `--- Day N: Title ---` and `--- Part Two ---` headings.
+#[derive(Debug, PartialEq)]
+pub struct Description {
+    pub title: String,
+    pub parts: Vec<String>,
+}
+impl Description {
+    pub fn of(source: &str) -> Option<Description> {
+        let comments = comments(source);
+        let heading = |line: &str| {
+            let line = line.trim_end();
+            line.strip_prefix("--- ").and_then(|line| line.strip_suffix(" ---")).map(str::to_string)
+        };
+
+        let title = comments.iter().find_map(|line| heading(line).filter(|h| h.starts_with("Day ")))?;
+        let start = comments.iter().position(|line| heading(line).as_ref() == Some(&title))? + 1;
+        let parts = comments[start..]
+            .split(|line| heading(line).is_some_and(|h| h.starts_with("Part ")))
+            .map(trim_blank_lines)
+            .collect();
+
+        Some(Description { title, parts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let source = "// --- Day 5: Alchemical Reduction ---\n// Units react.\n//\n// --- Part Two ---\n//\n// Remove one.\n\nfn main() {}\n";
+
+        assert_eq!(Description::of(source), Some(Description {
+            title: "Day 5: Alchemical Reduction".to_string(),
+            parts: vec!["Units react.".to_string(), "Remove one.".to_string()],
+        }));
+        assert_eq!(Description::of("fn main() {}\n"), None);
+    }
+}
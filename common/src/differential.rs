@@ -0,0 +1,93 @@
+use std::ops::Range;
+
+use crate::{Generate, Rng, Solution};
+
+fn answer<S: Solution>(input: &S::Input, part: u8) -> String {
+    match part {
+        1 => S::part1(input).to_string(),
+        2 => S::part2(input).to_string(),
+        _ => panic!("there is no part {}", part),
+    }
+}
+
+// Keeping a naive solver next to an optimized one makes the naive one a
+// reference for the other. Both solve the inputs `Naive` generates for the
+// `seeds` and have to give the same answers to the `parts`, otherwise this
+// panics with the seed and the input they disagree on.
+pub fn assert_same_answers<Naive, Optimized>(seeds: Range<u64>, size: usize, parts: &[u8])
+where
+    Naive: Solution + Generate,
+    Optimized: Solution,
+{
+    for seed in seeds {
+        let input = Naive::generate(&mut Rng::new(seed), size);
+        let naive = Naive::parse(&input).unwrap_or_else(|e| panic!("seed {}: {}\n{}", seed, e, input));
+        let optimized = Optimized::parse(&input).unwrap_or_else(|e| panic!("seed {}: {}\n{}", seed, e, input));
+
+        for &part in parts {
+            assert_eq!(answer::<Naive>(&naive, part), answer::<Optimized>(&optimized, part),
+                "part {} of seed {} differs for the input\n{}", part, seed, input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    struct Sum;
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            Ok(input.split_whitespace().map(|n| n.parse().unwrap()).collect())
+        }
+
+        fn part1(numbers: &Vec<u32>) -> u32 {
+            numbers.iter().sum()
+        }
+
+        fn part2(numbers: &Vec<u32>) -> u32 {
+            numbers.iter().max().copied().unwrap_or(0)
+        }
+    }
+    impl Generate for Sum {
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| rng.range(1..=9).to_string() + " ").collect()
+        }
+    }
+
+    // Sums up to the first 9 only, like an optimization gone wrong.
+    struct SumUntilNine;
+    impl Solution for SumUntilNine {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            Sum::parse(input)
+        }
+
+        fn part1(numbers: &Vec<u32>) -> u32 {
+            numbers.iter().take_while(|&&n| n != 9).sum()
+        }
+
+        fn part2(numbers: &Vec<u32>) -> u32 {
+            Sum::part2(numbers)
+        }
+    }
+
+    #[test]
+    fn same_answers() {
+        assert_same_answers::<Sum, SumUntilNine>(0..10, 20, &[2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1 of seed 0 differs")]
+    fn different_answers() {
+        assert_same_answers::<Sum, SumUntilNine>(0..10, 20, &[1, 2]);
+    }
+}
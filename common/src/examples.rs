@@ -0,0 +1,41 @@
+use crate::description::comments;
+
+// Every day's source starts with the puzzle description as `//` comments.
+// Returns the example in it that starts with `first_line` and is `lines`
+// long, without the comment markers, so tests use the exact text of the
+// puzzle instead of a retyped copy.
+pub fn example(source: &str, first_line: &str, lines: usize) -> String {
+    let comments = comments(source);
+
+    let start = comments.iter()
+        .position(|line| line.trim_end() == first_line)
+        .unwrap_or_else(|| panic!("No example starting with {:?}", first_line));
+
+    comments[start..start + lines].join("\n")
+}
+
+// Generates a test per example that parses its input and checks the answers
+// given for each part against their `Display` output. Attributes like
+// `#[ignore]` go on the test:
+//
+//     examples! {
+//         Day05,
+//         larger_example: "dabAcCaCBAcCcaDA" => part1: 10, part2: 4;
+//     }
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty, $($(#[$attribute:meta])* $name:ident: $input:expr => $($part:ident: $expected:expr),+;)+) => {
+        $(
+            #[test]
+            $(#[$attribute])*
+            fn $name() {
+                use $crate::Solution;
+                let input = <$solution>::parse(&$input).unwrap();
+                $(
+                    assert_eq!(<$solution>::$part(&input).to_string(), $expected.to_string(),
+                        "{} of {}", stringify!($part), stringify!($name));
+                )+
+            }
+        )+
+    };
+}
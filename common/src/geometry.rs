@@ -0,0 +1,189 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Range, Sub};
+
+// Coordinates as the puzzles use them: x grows to the right and y grows
+// downwards, like the lines of the input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+impl Point {
+    pub fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+// A movement from one point to another, e.g. a velocity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: i32,
+    pub y: i32,
+}
+impl Vector {
+    pub fn new(x: i32, y: i32) -> Vector {
+        Vector { x, y }
+    }
+}
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, times: i32) -> Vector {
+        Vector::new(self.x * times, self.y * times)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+impl Direction {
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_left().turn_left()
+    }
+
+    pub fn horizontal(self) -> bool {
+        self == Direction::Left || self == Direction::Right
+    }
+
+    // One step in this direction, up is towards smaller y.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+        }
+    }
+}
+
+// The smallest rectangle containing some points, `min` and `max` included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+impl BoundingBox {
+    pub fn of<I: IntoIterator<Item = Point>>(points: I) -> Option<BoundingBox> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(BoundingBox { min: first, max: first }, |b, p| BoundingBox {
+            min: Point::new(b.min.x.min(p.x), b.min.y.min(p.y)),
+            max: Point::new(b.max.x.max(p.x), b.max.y.max(p.y)),
+        }))
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn x_range(&self) -> Range<i32> {
+        self.min.x..self.max.x + 1
+    }
+
+    pub fn y_range(&self) -> Range<i32> {
+        self.min.y..self.max.y + 1
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.x_range().contains(&point.x) && self.y_range().contains(&point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -1);
+
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(a + (b - a), b);
+    }
+
+    #[test]
+    fn turning() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right().turn_right(), Direction::Up.reverse());
+        assert_eq!(Point::new(2, 2) + Direction::Up.vector() * 2, Point::new(2, 0));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = vec![Point::new(3, -2), Point::new(-1, 4), Point::new(0, 0)];
+        let bounds = BoundingBox::of(points).unwrap();
+
+        assert_eq!((bounds.min, bounds.max), (Point::new(-1, -2), Point::new(3, 4)));
+        assert_eq!((bounds.width(), bounds.height()), (5, 7));
+        assert!(bounds.contains(Point::new(3, 4)) && !bounds.contains(Point::new(4, 4)));
+        assert_eq!(BoundingBox::of(Vec::new()), None);
+    }
+}
@@ -0,0 +1,191 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::geometry::Point;
+
+// A rectangle of cells covering the coordinates in `x` and `y`. The ranges
+// don't have to start at 0, so a puzzle can keep its own (even negative)
+// coordinates instead of shifting them around.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    x: Range<i32>,
+    y: Range<i32>,
+    cells: Vec<T>,
+}
+
+// All positions of the ranges row by row, from the top left to the bottom right.
+fn positions(x: Range<i32>, y: Range<i32>) -> impl Iterator<Item = (i32, i32)> {
+    y.flat_map(move |y| x.clone().map(move |x| (x, y)))
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(i32, i32) -> T>(x: Range<i32>, y: Range<i32>, mut f: F) -> Grid<T> {
+        let cells = positions(x.clone(), y.clone()).map(|(x, y)| f(x, y)).collect();
+        Grid { x, y, cells }
+    }
+
+    pub fn new(x: Range<i32>, y: Range<i32>, fill: T) -> Grid<T> where T: Clone {
+        Grid::from_fn(x, y, |_, _| fill.clone())
+    }
+
+    // The rows start at 0,0 and shorter rows are padded with `fill`.
+    pub fn from_rows(rows: Vec<Vec<T>>, fill: T) -> Grid<T> where T: Clone {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+
+        Grid { x: 0..width as i32, y: 0..height as i32, cells }
+    }
+
+    pub fn x_range(&self) -> Range<i32> {
+        self.x.clone()
+    }
+
+    pub fn y_range(&self) -> Range<i32> {
+        self.y.clone()
+    }
+
+    pub fn width(&self) -> usize {
+        self.x.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.y.len()
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        self.x.contains(&x) && self.y.contains(&y)
+    }
+
+    pub fn on_edge(&self, x: i32, y: i32) -> bool {
+        self.contains(x, y) &&
+            (x == self.x.start || x == self.x.end - 1 || y == self.y.start || y == self.y.end - 1)
+    }
+
+    fn index_of(&self, x: i32, y: i32) -> Option<usize> {
+        if self.contains(x, y) {
+            Some((y - self.y.start) as usize * self.width() + (x - self.x.start) as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.index_of(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    // The cells of row y from left to right.
+    pub fn row(&self, y: i32) -> &[T] {
+        assert!(self.y.contains(&y), "row {} is outside of the grid", y);
+        let start = (y - self.y.start) as usize * self.width();
+        &self.cells[start..start + self.width()]
+    }
+
+    // The cells above, below, left and right of x,y that are inside the grid.
+    pub fn neighbours(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)].iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(move |&(x, y)| self.contains(x, y))
+    }
+
+    // Like `neighbours`, but including the diagonal ones.
+    pub fn neighbours_with_diagonals(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        positions(x - 1..x + 2, y - 1..y + 2)
+            .filter(move |&position| position != (x, y) && self.contains(position.0, position.1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        positions(self.x_range(), self.y_range())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        positions(self.x_range(), self.y_range()).zip(self.cells.iter_mut())
+    }
+
+    pub fn edge(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells().filter(move |&((x, y), _)| self.on_edge(x, y))
+    }
+
+    // One line per row, each cell drawn as the character `draw` returns for it.
+    pub fn render<F: Fn(&T) -> char>(&self, draw: F) -> String {
+        let mut rendered = String::with_capacity((self.width() + 1) * self.height());
+
+        for row in self.cells.chunks(self.width().max(1)) {
+            rendered.extend(row.iter().map(&draw));
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+}
+impl<T> Index<(i32, i32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (i32, i32)) -> &T {
+        self.get(x, y).unwrap_or_else(|| panic!("{},{} is outside of the grid", x, y))
+    }
+}
+impl<T> IndexMut<(i32, i32)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut T {
+        self.get_mut(x, y).unwrap_or_else(|| panic!("{},{} is outside of the grid", x, y))
+    }
+}
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_origin() {
+        let mut grid = Grid::new(-2..1, -1..1, '.');
+        grid[(-2, -1)] = '#';
+        grid[(0, 0)] = '#';
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(1, 0), None);
+        assert_eq!(grid.render(|c| *c), "#..\n..#\n");
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::from_fn(0..3, 0..3, |x, y| x + y);
+
+        assert_eq!(grid.neighbours(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours(1, 1).count(), 4);
+        assert_eq!(grid.neighbours_with_diagonals(0, 2).collect::<Vec<_>>(), vec![(0, 1), (1, 1), (1, 2)]);
+        assert_eq!(grid.neighbours_with_diagonals(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn rows_are_padded() {
+        let grid = Grid::from_rows(vec![vec!['/', '-', '\\'], vec!['|']], ' ');
+
+        assert_eq!(grid.render(|c| *c), "/-\\\n|  \n");
+        assert_eq!(grid.edge().count(), 6);
+        assert_eq!(grid.cells().filter(|(_, c)| **c == ' ').count(), 2);
+    }
+}
@@ -0,0 +1,46 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::process;
+
+use crate::Solution;
+
+// Reads the puzzle input from a file, or from stdin when the path is `-`.
+pub fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        Ok(contents)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+// How to refer to the input in error messages.
+pub fn input_name(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+// Parses the input of a day's binary: the file given as first argument,
+// stdin for `-`, or `default` (the day's own input.txt) without arguments.
+pub fn input<S: Solution>(default: &str) -> S::Input {
+    input_at::<S>(&env::args().nth(1).unwrap_or_else(|| default.to_string()))
+}
+
+// Like `input`, for binaries that take other arguments as well.
+pub fn input_at<S: Solution>(path: &str) -> S::Input {
+    let contents = read_input(path).unwrap_or_else(|e| {
+        eprintln!("Could not read {}: {}", input_name(path), e);
+        process::exit(1);
+    });
+
+    S::parse(&contents).unwrap_or_else(|e| {
+        eprintln!("{}", e.in_file(input_name(path)));
+        process::exit(1);
+    })
+}
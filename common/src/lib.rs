@@ -0,0 +1,51 @@
+mod description;
+mod differential;
+mod examples;
+mod geometry;
+mod grid;
+mod input;
+mod parse;
+mod random;
+
+use std::fmt;
+use std::fmt::Display;
+
+pub use crate::description::Description;
+pub use crate::differential::assert_same_answers;
+pub use crate::examples::example;
+pub use crate::geometry::{BoundingBox, Direction, Point, Vector};
+pub use crate::grid::Grid;
+pub use crate::input::{input, input_at, input_name, read_input};
+pub use crate::parse::{lines, non_blank_lines, Line, ParseError};
+pub use crate::random::Rng;
+
+// Every day parses its puzzle input once and answers both parts from the
+// parsed input, so parsing and solving can be called (and timed) separately.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+// Random but valid puzzle inputs to stress and property test the solvers.
+// `size` is roughly the number of items in the input and the same seed always
+// gives the same input.
+pub trait Generate {
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+// An answer that doesn't exist for every input, e.g. when no claim is intact.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Maybe<T>(pub Option<T>);
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(ref answer) => answer.fmt(f),
+            None => write!(f, "none"),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Where and why a puzzle input couldn't be parsed. Lines and columns start at
+// 1 like in an editor. The file is only known to whoever read the input, so
+// it's added with `in_file`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    // For problems with the input as a whole, e.g. when it has no coordinates at all.
+    pub fn at_end(input: &str, message: &str) -> ParseError {
+        let last_line = input.lines().last().unwrap_or("");
+        ParseError::new(input.lines().count().max(1), last_line.chars().count() + 1, "", message)
+    }
+
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref file) = self.file {
+            write!(f, "{}:", file)?;
+        }
+
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, ", found `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+impl Error for ParseError {}
+
+// A line of the puzzle input and its line number, to report errors in it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+impl<'a> Line<'a> {
+    // An error about `text`, which starts at byte `start` of this line.
+    pub fn error(&self, start: usize, text: &str, message: &str) -> ParseError {
+        let column = self.text[..start].chars().count() + 1;
+        ParseError::new(self.number, column, text, message)
+    }
+
+    pub fn parse<T: FromStr>(&self, start: usize, text: &str, expected: &str) -> Result<T, ParseError> {
+        text.parse().map_err(|_| self.error(start, text, &format!("expected {}", expected)))
+    }
+
+    // The whitespace separated fields of the line and the byte each starts at.
+    pub fn fields(&self) -> Vec<(usize, &'a str)> {
+        let mut fields = Vec::new();
+        let mut start = None;
+
+        for (i, c) in self.text.char_indices() {
+            match (c.is_whitespace(), start) {
+                (true, Some(s)) => {
+                    fields.push((s, &self.text[s..i]));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => (),
+            }
+        }
+
+        if let Some(s) = start {
+            fields.push((s, &self.text[s..]));
+        }
+
+        fields
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line { number: i + 1, text })
+}
+
+// Most inputs have one item per line and blank lines carry no meaning.
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines(input).filter(|line| !line.text.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_the_offending_text() {
+        let line = lines("+1\n-2 x\n").nth(1).unwrap();
+        let error = line.parse::<i32>(3, "x", "a number").unwrap_err().in_file("input.txt");
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.to_string(), "input.txt:2:4: expected a number, found `x`");
+    }
+
+    #[test]
+    fn errors_at_the_end_of_the_input() {
+        assert_eq!(ParseError::at_end("1, 1\n1, 6\n", "too few").to_string(), "2:5: too few");
+        assert_eq!(ParseError::at_end("", "empty").to_string(), "1:1: empty");
+    }
+
+    #[test]
+    fn fields_with_their_start() {
+        let line = Line { number: 1, text: "2 3  0\t3 " };
+        assert_eq!(line.fields(), vec![(0, "2"), (2, "3"), (5, "0"), (7, "3")]);
+    }
+}
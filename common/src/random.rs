@@ -0,0 +1,75 @@
+use std::ops::RangeInclusive;
+
+// A small seedable random number generator (SplitMix64). Generated inputs only
+// need to be reproducible from their seed, not cryptographically random.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // An index into something of length `len`, which mustn't be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "can't pick from nothing");
+        (self.next_u64() % len as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i32>) -> i32 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        (i64::from(start) + (self.next_u64() % (i64::from(end) - i64::from(start) + 1) as u64) as i64) as i32
+    }
+
+    // True with a probability of `percent` in 100.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.range(-3..=3)).collect::<Vec<i32>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (-3..=3).contains(n)));
+    }
+
+    #[test]
+    fn shuffle_keeps_every_item() {
+        let mut items: Vec<u8> = (0..20).collect();
+        Rng::new(1).shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<u8>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<u8>>());
+    }
+}
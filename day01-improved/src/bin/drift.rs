@@ -0,0 +1,62 @@
+extern crate common;
+extern crate day01_improved;
+
+use day01_improved::{drift_report, trajectory, Day01};
+use std::env;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::process;
+
+const USAGE: &str = "Usage: drift [<path>|-] [--passes <n>] [--csv <path>]";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+// Reports how the frequency drifts over the passes over the changes, by default
+// until the first frequency repeats. `--csv` also writes every frequency of
+// these passes to a file.
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut path = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt").to_string();
+    let mut passes = None;
+    let mut csv = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--passes" => passes = Some(args.next().and_then(|n| n.parse().ok())
+                .unwrap_or_else(|| fail("--passes has to be a number"))),
+            "--csv" => csv = Some(args.next().unwrap_or_else(|| fail(USAGE))),
+            arg if arg.starts_with("--") => fail(USAGE),
+            _ => path = arg,
+        }
+    }
+
+    let changes = common::input_at::<Day01>(&path);
+    let first_repeat = day01_improved::first_repeat(&changes);
+    let passes = passes.unwrap_or_else(|| first_repeat.as_ref().map_or(1, |repeat| repeat.pass));
+    let report = drift_report(&changes, passes);
+
+    println!("Drift per pass: {:+}", report.drift);
+    println!("Frequencies in {} passes: {} to {}", report.passes, report.min, report.max);
+    match report.first_repeat {
+        Some(repeat) => println!("First repeat: {} in pass {} on line {}", repeat.frequency, repeat.pass, repeat.line),
+        None => println!("First repeat: never"),
+    }
+    println!("Frequencies reached more than once in {} passes: {}", report.passes, report.repeats.len());
+    for (frequency, times) in &report.repeats {
+        println!("    {} ({} times)", frequency, times);
+    }
+
+    if let Some(csv) = csv {
+        let file = File::create(&csv).unwrap_or_else(|e| fail(&format!("Could not write {}: {}", csv, e)));
+        let mut out = BufWriter::new(file);
+
+        let written = writeln!(out, "pass,line,change,frequency").and_then(|_| {
+            trajectory(&changes, passes)
+                .try_for_each(|step| writeln!(out, "{},{},{},{}", step.pass, step.line, step.change, step.frequency))
+        }).and_then(|_| out.flush());
+        written.unwrap_or_else(|e| fail(&format!("Could not write {}: {}", csv, e)));
+    }
+}
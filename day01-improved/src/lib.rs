@@ -0,0 +1,258 @@
+// --- Day 1: Chronal Calibration ---
+// "We've detected some temporal anomalies," one of Santa's Elves at the Temporal Anomaly Research and Detection Instrument Station tells you. She sounded pretty worried when she called you down here. "At 500-year intervals into the past, someone has been changing Santa's history!"
+//
+// "The good news is that the changes won't propagate to our time stream for another 25 days, and we have a device" - she attaches something to your wrist - "that will let you fix the changes with no such propagation delay. It's configured to send you 500 years further into the past every few days; that was the best we could do on such short notice."
+//
+// "The bad news is that we are detecting roughly fifty anomalies throughout time; the device will indicate fixed anomalies with stars. The other bad news is that we only have one device and you're the best person for the job! Good lu--" She taps a button on the device and you suddenly feel like you're falling. To save Christmas, you need to get all fifty stars by December 25th.
+//
+// Collect stars by solving puzzles. Two puzzles will be made available on each day in the advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
+//
+// After feeling like you've been falling for a few minutes, you look at the device's tiny screen. "Error: Device must be calibrated before first use. Frequency drift detected. Cannot maintain destination lock." Below the message, the device shows a sequence of changes in frequency (your puzzle input). A value like +6 means the current frequency increases by 6; a value like -3 means the current frequency decreases by 3.
+//
+// For example, if the device displays frequency changes of +1, -2, +3, +1, then starting from a frequency of zero, the following changes would occur:
+//
+// Current frequency  0, change of +1; resulting frequency  1.
+// Current frequency  1, change of -2; resulting frequency -1.
+// Current frequency -1, change of +3; resulting frequency  2.
+// Current frequency  2, change of +1; resulting frequency  3.
+// In this example, the resulting frequency is 3.
+//
+// Here are other example situations:
+//
+// +1, +1, +1 results in  3
+// +1, +1, -2 results in  0
+// -1, -2, -3 results in -6
+// Starting with a frequency of zero, what is the resulting frequency after all of the changes in frequency have been applied?
+//
+// --- Part Two ---
+// You notice that the device repeats the same frequency change list over and over. To calibrate the device, you need to find the first frequency it reaches twice.
+//
+// For example, using the same list of changes above, the device would loop as follows:
+//
+// Current frequency  0, change of +1; resulting frequency  1.
+// Current frequency  1, change of -2; resulting frequency -1.
+// Current frequency -1, change of +3; resulting frequency  2.
+// Current frequency  2, change of +1; resulting frequency  3.
+// (At this point, the device continues from the start of the list.)
+// Current frequency  3, change of +1; resulting frequency  4.
+// Current frequency  4, change of -2; resulting frequency  2, which has already been seen.
+// In this example, the first frequency reached twice is 2. Note that your device might need to repeat its list of frequency changes many times before a duplicate frequency is found, and that duplicates might be found while in the middle of processing the list.
+//
+// Here are other examples:
+//
+// +1, -1 first reaches 0 twice.
+// +3, +3, +4, -2, -4 first reaches 10 twice.
+// -6, +3, +8, +5, -6 first reaches 5 twice.
+// +7, +7, -2, -7, -4 first reaches 14 twice.
+
+extern crate common;
+extern crate day01;
+
+use common::{non_blank_lines, Generate, Maybe, ParseError, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+// The first frequency the device reaches twice, in which pass over the list of
+// changes (counting from 1) and on which line of it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Repeat {
+    pub frequency: i32,
+    pub pass: usize,
+    pub line: usize,
+}
+
+// Every pass shifts the frequencies of the first one by the total change of a
+// pass, the drift. So a line reaches a frequency of the first pass again only
+// if both have the same remainder divided by the drift, and it catches up with
+// the next one of its remainder in the direction of the drift after their
+// difference / drift passes. Sorting the first pass by remainder finds the
+// next one for every line, instead of cycling through the list forever when
+// nothing ever repeats.
+pub fn first_repeat(changes: &[i32]) -> Option<Repeat> {
+    let mut frequency = 0;
+    let mut seen = HashSet::new();
+    let mut first_pass = Vec::with_capacity(changes.len());
+    seen.insert(frequency);
+
+    for (i, change) in changes.iter().enumerate() {
+        frequency += change;
+
+        if !seen.insert(frequency) {
+            return Some(Repeat { frequency, pass: 1, line: i + 1 });
+        }
+
+        first_pass.push((frequency, Some(i + 1)));
+    }
+
+    // Without a drift the last line would have reached 0 again, unless there are no lines
+    let drift = frequency;
+    if drift == 0 {
+        return None;
+    }
+
+    // Flipped for a negative drift, so lines always catch up with larger frequencies.
+    // The starting 0 can be caught up with, but it doesn't move on by itself.
+    let mut frequencies: Vec<(i32, i32, Option<usize>)> = first_pass.into_iter()
+        .chain(iter::once((0, None)))
+        .map(|(frequency, line)| (frequency.rem_euclid(drift), frequency * drift.signum(), line))
+        .collect();
+    frequencies.sort();
+
+    frequencies.windows(2)
+        .filter(|pair| pair[0].0 == pair[1].0)
+        .filter_map(|pair| {
+            let line = pair[0].2?;
+            let passes = (pair[1].1 - pair[0].1) / drift.abs();
+            Some((passes as usize + 1, line, pair[1].1 * drift.signum()))
+        })
+        .min()
+        .map(|(pass, line, frequency)| Repeat { frequency, pass, line })
+}
+
+// One change of a pass over the list and the frequency it results in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Step {
+    pub pass: usize,
+    pub line: usize,
+    pub change: i32,
+    pub frequency: i32,
+}
+
+// Every frequency the device goes through in the first `passes` passes.
+pub fn trajectory(changes: &[i32], passes: usize) -> impl Iterator<Item = Step> + '_ {
+    changes.iter().enumerate().cycle()
+        .take(changes.len() * passes)
+        .enumerate()
+        .scan(0, move |frequency, (i, (line, &change))| {
+            *frequency += change;
+            Some(Step { pass: i / changes.len() + 1, line: line + 1, change, frequency: *frequency })
+        })
+}
+
+// How the frequency drifts over the first `passes` passes, the starting 0 included.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriftReport {
+    pub passes: usize,
+    // The change of the frequency in every pass, which is also part 1's answer
+    pub drift: i32,
+    pub min: i32,
+    pub max: i32,
+    pub first_repeat: Option<Repeat>,
+    // The frequencies reached more than once and how often, in the order they first repeat
+    pub repeats: Vec<(i32, usize)>,
+}
+
+pub fn drift_report(changes: &[i32], passes: usize) -> DriftReport {
+    let mut min = 0;
+    let mut max = 0;
+    let mut reached = HashMap::new();
+    let mut repeated = Vec::new();
+    reached.insert(0, 1);
+
+    for step in trajectory(changes, passes) {
+        min = min.min(step.frequency);
+        max = max.max(step.frequency);
+
+        let times = reached.entry(step.frequency).or_insert(0);
+        *times += 1;
+        if *times == 2 {
+            repeated.push(step.frequency);
+        }
+    }
+
+    DriftReport {
+        passes,
+        drift: changes.iter().sum(),
+        min,
+        max,
+        first_repeat: first_repeat(changes),
+        repeats: repeated.into_iter().map(|frequency| (frequency, reached[&frequency])).collect(),
+    }
+}
+
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = Maybe<i32>;
+
+    fn parse(contents: &str) -> Result<Vec<i32>, ParseError> {
+        non_blank_lines(contents)
+            .map(|line| line.parse(0, line.text.trim(), "a frequency change like +6 or -3"))
+            .collect()
+    }
+
+    // 595
+    fn part1(changes: &Vec<i32>) -> i32 {
+        changes.iter().sum()
+    }
+
+    // 80598
+    fn part2(changes: &Vec<i32>) -> Maybe<i32> {
+        Maybe(first_repeat(changes).map(|repeat| repeat.frequency))
+    }
+}
+
+// The same lists as for the naive solution, so both are tested on the same inputs.
+impl Generate for Day01 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        day01::Day01::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::examples;
+
+    fn changes(example: &str) -> String {
+        example.replace(", ", "\n")
+    }
+
+    examples! {
+        Day01,
+        sequence: changes("+1, -2, +3, +1") => part1: 3, part2: 2;
+        increasing: changes("+1, +1, +1") => part1: 3;
+        back_to_zero: changes("+1, +1, -2") => part1: 0;
+        decreasing: changes("-1, -2, -3") => part1: -6;
+        first_reaches_0_twice: changes("+1, -1") => part2: 0;
+        first_reaches_10_twice: changes("+3, +3, +4, -2, -4") => part2: 10;
+        first_reaches_5_twice: changes("-6, +3, +8, +5, -6") => part2: 5;
+        first_reaches_14_twice: changes("+7, +7, -2, -7, -4") => part2: 14;
+        never_repeats: changes("+1, +1") => part2: "none";
+        no_changes: "" => part2: "none";
+    }
+
+    #[test]
+    fn pass_and_line_of_the_repeat() {
+        assert_eq!(first_repeat(&[1, -1]), Some(Repeat { frequency: 0, pass: 1, line: 2 }));
+        assert_eq!(first_repeat(&[3, 3, 4, -2, -4]), Some(Repeat { frequency: 10, pass: 2, line: 2 }));
+        assert_eq!(first_repeat(&[-6, 3, 8, 5, -6]), Some(Repeat { frequency: 5, pass: 3, line: 2 }));
+        assert_eq!(first_repeat(&[2, -1, -3]), Some(Repeat { frequency: 0, pass: 2, line: 1 }));
+        assert_eq!(first_repeat(&[1, 1, -10]), None);
+    }
+
+    #[test]
+    fn drift_over_two_passes() {
+        let changes = [1, -2, 3, 1];
+        let frequencies: Vec<(usize, usize, i32)> = trajectory(&changes, 2)
+            .map(|step| (step.pass, step.line, step.frequency))
+            .collect();
+
+        assert_eq!(frequencies, vec![(1, 1, 1), (1, 2, -1), (1, 3, 2), (1, 4, 3), (2, 1, 4), (2, 2, 2), (2, 3, 5), (2, 4, 6)]);
+        assert_eq!(drift_report(&changes, 2), DriftReport {
+            passes: 2,
+            drift: 3,
+            min: -1,
+            max: 6,
+            first_repeat: Some(Repeat { frequency: 2, pass: 2, line: 2 }),
+            repeats: vec![(2, 2)],
+        });
+    }
+
+    #[test]
+    fn corrupted_change() {
+        let error = Day01::parse(&changes("+1, -2, +x, +1")).unwrap_err();
+        assert_eq!(error.to_string(), "3:1: expected a frequency change like +6 or -3, found `+x`");
+    }
+}
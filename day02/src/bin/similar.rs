@@ -0,0 +1,63 @@
+extern crate common;
+extern crate day02;
+
+use day02::{clusters, similar_ids, side_by_side, Day02, Metric};
+use std::env;
+use std::io::{self, IsTerminal};
+use std::process;
+
+const USAGE: &str = "Usage: similar [<path>|-] [--metric hamming|levenshtein|damerau] [--distance <n>] [--top <n>]";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn number(value: Option<String>, name: &str) -> usize {
+    value.and_then(|n| n.parse().ok()).unwrap_or_else(|| fail(&format!("{} has to be a number", name)))
+}
+
+// Lists the most similar box IDs lined up above each other and the clusters of
+// IDs linked by similar ones, to check part 2's answer by hand. Differences are
+// highlighted in a terminal (unless NO_COLOR is set) and marked otherwise.
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut path = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt").to_string();
+    let mut metric = Metric::Hamming;
+    let mut distance = 1;
+    let mut top = 10;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--metric" => metric = match args.next().as_deref() {
+                Some("hamming") => Metric::Hamming,
+                Some("levenshtein") => Metric::Levenshtein,
+                Some("damerau") => Metric::Damerau,
+                _ => fail("--metric has to be one of hamming, levenshtein or damerau"),
+            },
+            "--distance" => distance = number(args.next(), "--distance"),
+            "--top" => top = number(args.next(), "--top"),
+            arg if arg.starts_with("--") => fail(USAGE),
+            _ => path = arg,
+        }
+    }
+
+    let ids = common::input_at::<Day02>(&path);
+    let ansi = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    let pairs = similar_ids(&ids, distance, metric);
+    let mut best = pairs.clone();
+    best.sort_by_key(|pair| pair.distance);
+
+    println!("{} pairs of IDs at most {} apart ({:?}), the closest {}:", pairs.len(), distance, metric, top.min(pairs.len()));
+    for pair in best.iter().take(top) {
+        println!("\nIDs {} and {}, {} apart:", pair.first + 1, pair.second + 1, pair.distance);
+        print!("{}", side_by_side(&ids[pair.first], &ids[pair.second], metric, ansi));
+    }
+
+    let clusters = clusters(&pairs);
+    println!("\n{} clusters of similar IDs:", clusters.len());
+    for cluster in clusters {
+        let members: Vec<String> = cluster.iter().map(|&i| format!("{} {}", i + 1, ids[i])).collect();
+        println!("    {}", members.join(", "));
+    }
+}
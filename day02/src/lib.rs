@@ -0,0 +1,568 @@
+// --- Day 2: Inventory Management System ---
+// You stop falling through time, catch your breath, and check the screen on the device. "Destination reached. Current Year: 1518. Current Location: North Pole Utility Closet 83N10." You made it! Now, to find those anomalies.
+//
+// Outside the utility closet, you hear footsteps and a voice. "...I'm not sure either. But now that so many people have chimneys, maybe he could sneak in that way?" Another voice responds, "Actually, we've been working on a new kind of suit that would let him fit through tight spaces like that. But, I heard that a few days ago, they lost the prototype fabric, the design plans, everything! Nobody on the team can even seem to remember important details of the project!"
+//
+// "Wouldn't they have had enough fabric to fill several boxes in the warehouse? They'd be stored together, so the box IDs should be similar. Too bad it would take forever to search the warehouse for two similar box IDs..." They walk too far away to hear any more.
+//
+// Late at night, you sneak to the warehouse - who knows what kinds of paradoxes you could cause if you were discovered - and use your fancy wrist device to quickly scan every box and produce a list of the likely candidates (your puzzle input).
+//
+// To make sure you didn't miss any, you scan the likely candidate boxes again, counting the number that have an ID containing exactly two of any letter and then separately counting those with exactly three of any letter. You can multiply those two counts together to get a rudimentary checksum and compare it to what your device predicts.
+//
+// For example, if you see the following box IDs:
+//
+// abcdef contains no letters that appear exactly two or three times.
+// bababc contains two a and three b, so it counts for both.
+// abbcde contains two b, but no letter appears exactly three times.
+// abcccd contains three c, but no letter appears exactly two times.
+// aabcdd contains two a and two d, but it only counts once.
+// abcdee contains two e.
+// ababab contains three a and three b, but it only counts once.
+// Of these box IDs, four of them contain a letter which appears exactly twice, and three of them contain a letter which appears exactly three times. Multiplying these together produces a checksum of 4 * 3 = 12.
+//
+// What is the checksum for your list of box IDs?
+
+// --- Part Two ---
+// Confident that your list of box IDs is complete, you're ready to find the boxes full of prototype fabric.
+//
+// The boxes will have IDs which differ by exactly one character at the same position in both strings. For example, given the following box IDs:
+//
+// abcde
+// fghij
+// klmno
+// pqrst
+// fguij
+// axcye
+// wvxyz
+// The IDs abcde and axcye are close, but they differ by two characters (the second and fourth). However, the IDs fghij and fguij differ by exactly one character, the third (h and u). Those must be the correct boxes.
+//
+// What letters are common between the two correct box IDs? (In the example above, this is found by removing the differing character from either ID, producing fgij.)
+
+extern crate common;
+
+use common::{non_blank_lines, Generate, Maybe, ParseError, Rng, Solution};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+// PART 1
+
+// How often each letter appears in an ID. Letters are chars rather than bytes,
+// so a letter beyond ASCII isn't counted once per byte.
+pub fn letter_counts(id: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for letter in id.chars() {
+        *counts.entry(letter).or_insert(0) += 1;
+    }
+    counts
+}
+
+// For every multiplicity, how many IDs have at least one letter that appears
+// exactly that many times.
+pub fn multiplicity_histogram<S: AsRef<str>>(ids: &[S]) -> BTreeMap<usize, usize> {
+    let mut histogram = BTreeMap::new();
+
+    for id in ids {
+        let multiplicities: BTreeSet<usize> = letter_counts(id.as_ref()).into_values().collect();
+        for multiplicity in multiplicities {
+            *histogram.entry(multiplicity).or_insert(0) += 1;
+        }
+    }
+
+    histogram
+}
+
+// The product of the histogram's counts for the given multiplicities. The
+// puzzle's checksum uses 2 and 3.
+pub fn checksum<S: AsRef<str>>(ids: &[S], multiplicities: &[usize]) -> usize {
+    let histogram = multiplicity_histogram(ids);
+    multiplicities.iter().map(|m| histogram.get(m).cloned().unwrap_or(0)).product()
+}
+
+// PART 2
+
+// Two IDs of the same length, by their index in the list, and the positions
+// (in chars) at which their letters differ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearDuplicate {
+    pub first: usize,
+    pub second: usize,
+    pub positions: Vec<usize>,
+}
+
+// Where block `block` of `blocks` starts in an ID of length `len`.
+fn block_start(block: usize, blocks: usize, len: usize) -> usize {
+    block * len / blocks
+}
+
+// The positions at which two IDs differ, unless there are more than `max`.
+fn differences(a: &[char], b: &[char], max: usize) -> Option<Vec<usize>> {
+    let mut positions = Vec::new();
+
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        if x != y {
+            if positions.len() == max {
+                return None;
+            }
+            positions.push(i);
+        }
+    }
+
+    Some(positions)
+}
+
+// All pairs of IDs that differ in at most `max_distance` positions, ordered by
+// their first and then their second ID. Split into `max_distance + 1` blocks,
+// two such IDs have at least one block in common, so only IDs sharing a block
+// are compared instead of every pair. A pair is only reported for the first
+// block they share.
+pub fn near_duplicates<S: AsRef<str>>(ids: &[S], max_distance: usize) -> Vec<NearDuplicate> {
+    let ids: Vec<Vec<char>> = ids.iter().map(|id| id.as_ref().chars().collect()).collect();
+    let blocks = max_distance + 1;
+    let mut buckets: HashMap<(usize, usize, &[char]), Vec<usize>> = HashMap::new();
+    let mut pairs = Vec::new();
+
+    for (i, id) in ids.iter().enumerate() {
+        for block in 0..blocks {
+            let (start, end) = (block_start(block, blocks, id.len()), block_start(block + 1, blocks, id.len()));
+            buckets.entry((id.len(), block, &id[start..end])).or_default().push(i);
+        }
+    }
+
+    for (&(len, block, _), bucket) in &buckets {
+        for (n, &first) in bucket.iter().enumerate() {
+            for &second in &bucket[n + 1..] {
+                let positions = match differences(&ids[first], &ids[second], max_distance) {
+                    Some(positions) => positions,
+                    None => continue,
+                };
+                let first_shared = (0..blocks).find(|&b| {
+                    let block = block_start(b, blocks, len)..block_start(b + 1, blocks, len);
+                    !positions.iter().any(|p| block.contains(p))
+                });
+
+                if first_shared == Some(block) {
+                    pairs.push(NearDuplicate { first, second, positions });
+                }
+            }
+        }
+    }
+
+    pairs.sort_by_key(|pair| (pair.first, pair.second));
+    pairs
+}
+
+// How to tell how far apart two IDs are. Hamming only compares IDs of the same
+// length letter by letter, Levenshtein also counts inserted and deleted letters,
+// and Damerau also swapped neighbours (each letter only swapped once).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    Hamming,
+    Levenshtein,
+    Damerau,
+}
+impl Metric {
+    // None for IDs of different lengths under Hamming.
+    pub fn distance(self, a: &str, b: &str) -> Option<usize> {
+        let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+
+        match self {
+            Metric::Hamming if a.len() != b.len() => None,
+            Metric::Hamming => Some(a.iter().zip(&b).filter(|(x, y)| x != y).count()),
+            _ => Some(edit_table(&a, &b, self == Metric::Damerau)[a.len()][b.len()]),
+        }
+    }
+}
+
+// The fewest edits turning each prefix of `a` into each prefix of `b`.
+fn edit_table(a: &[char], b: &[char], transpositions: bool) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            table[i][j] = if i == 0 || j == 0 {
+                i + j
+            } else {
+                let substitution = table[i - 1][j - 1] + (a[i - 1] != b[j - 1]) as usize;
+                let mut fewest = substitution.min(table[i - 1][j] + 1).min(table[i][j - 1] + 1);
+
+                if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    fewest = fewest.min(table[i - 2][j - 2] + 1);
+                }
+                fewest
+            };
+        }
+    }
+
+    table
+}
+
+// Two IDs lined up letter by letter, with None where one of them has a letter
+// the other one doesn't. Hamming lines up the letters at the same positions,
+// the edit distances line them up along the fewest edits.
+pub fn align(a: &str, b: &str, metric: Metric) -> Vec<(Option<char>, Option<char>)> {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+
+    if metric == Metric::Hamming {
+        return (0..a.len().max(b.len())).map(|i| (a.get(i).cloned(), b.get(i).cloned())).collect();
+    }
+
+    let table = edit_table(&a, &b, metric == Metric::Damerau);
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut columns = Vec::new();
+
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && table[i][j] == table[i - 1][j - 1] + (a[i - 1] != b[j - 1]) as usize {
+            columns.push((Some(a[i - 1]), Some(b[j - 1])));
+            i -= 1;
+            j -= 1;
+        } else if metric == Metric::Damerau && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1]
+            && table[i][j] == table[i - 2][j - 2] + 1 {
+            columns.push((Some(a[i - 1]), Some(b[j - 1])));
+            columns.push((Some(a[i - 2]), Some(b[j - 2])));
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && table[i][j] == table[i - 1][j] + 1 {
+            columns.push((Some(a[i - 1]), None));
+            i -= 1;
+        } else {
+            columns.push((None, Some(b[j - 1])));
+            j -= 1;
+        }
+    }
+
+    columns.reverse();
+    columns
+}
+
+// The letters two IDs have in common once they are lined up.
+pub fn common_letters(a: &str, b: &str, metric: Metric) -> String {
+    align(a, b, metric).into_iter()
+        .filter_map(|(x, y)| if x.is_some() && x == y { x } else { None })
+        .collect()
+}
+
+// Two lined up IDs above each other. Their differences are highlighted in red
+// with `ansi`, or else marked with a `^` on a third line. `-` stands in for a
+// missing letter.
+pub fn side_by_side(a: &str, b: &str, metric: Metric, ansi: bool) -> String {
+    let columns = align(a, b, metric);
+    let mut lines = [String::new(), String::new(), String::new()];
+
+    for (x, y) in columns {
+        let differs = x != y;
+
+        for (line, letter) in lines.iter_mut().zip(&[x, y]) {
+            let letter = letter.unwrap_or('-');
+            if differs && ansi {
+                line.push_str(&format!("\x1b[1;31m{}\x1b[0m", letter));
+            } else {
+                line.push(letter);
+            }
+        }
+        lines[2].push(if differs { '^' } else { ' ' });
+    }
+
+    let marked = if ansi { 2 } else { 3 };
+    lines[..marked].iter().map(|line| line.trim_end().to_string() + "\n").collect()
+}
+
+// Groups of IDs linked by a chain of similar pairs, each ordered by index and
+// the largest group first. IDs without a similar one are left out.
+pub fn clusters(pairs: &[Similar]) -> Vec<Vec<usize>> {
+    let mut parents: HashMap<usize, usize> = HashMap::new();
+
+    fn root(parents: &mut HashMap<usize, usize>, id: usize) -> usize {
+        let parent = *parents.entry(id).or_insert(id);
+        if parent == id {
+            return id;
+        }
+        let root = root(parents, parent);
+        parents.insert(id, root);
+        root
+    }
+
+    for pair in pairs {
+        let (a, b) = (root(&mut parents, pair.first), root(&mut parents, pair.second));
+        parents.insert(a.max(b), a.min(b));
+    }
+
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    let ids: Vec<usize> = parents.keys().cloned().collect();
+    for id in ids {
+        let root = root(&mut parents, id);
+        groups.entry(root).or_default().push(id);
+    }
+
+    let mut clusters: Vec<Vec<usize>> = groups.into_values().collect();
+    for cluster in &mut clusters {
+        cluster.sort();
+    }
+    clusters.sort_by_key(|cluster| (Reverse(cluster.len()), cluster[0]));
+    clusters
+}
+
+// Two IDs by their index in the list and how far apart they are.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Similar {
+    pub first: usize,
+    pub second: usize,
+    pub distance: usize,
+}
+
+// Adds every way of deleting up to `max` letters from `id`, at or after `from`.
+fn add_deletions(id: &[char], from: usize, max: usize, keys: &mut HashSet<Vec<char>>) {
+    keys.insert(id.to_vec());
+
+    if max > 0 {
+        for i in from..id.len() {
+            let mut deleted = id.to_vec();
+            deleted.remove(i);
+            add_deletions(&deleted, i, max - 1, keys);
+        }
+    }
+}
+
+// All pairs of IDs at most `max_distance` apart, ordered by their first and then
+// their second ID. For the edit distances, each edit can be undone by deleting
+// a letter from one or both IDs, so IDs that close share one of the ways to
+// delete up to `max_distance` letters from them, and only those are compared.
+pub fn similar_ids<S: AsRef<str>>(ids: &[S], max_distance: usize, metric: Metric) -> Vec<Similar> {
+    if metric == Metric::Hamming {
+        return near_duplicates(ids, max_distance).into_iter()
+            .map(|pair| Similar { first: pair.first, second: pair.second, distance: pair.positions.len() })
+            .collect();
+    }
+
+    let mut buckets: HashMap<Vec<char>, Vec<usize>> = HashMap::new();
+    for (i, id) in ids.iter().enumerate() {
+        let mut keys = HashSet::new();
+        add_deletions(&id.as_ref().chars().collect::<Vec<char>>(), 0, max_distance, &mut keys);
+
+        for key in keys {
+            buckets.entry(key).or_default().push(i);
+        }
+    }
+
+    let candidates: BTreeSet<(usize, usize)> = buckets.values()
+        .flat_map(|bucket| bucket.iter().enumerate()
+            .flat_map(move |(n, &first)| bucket[n + 1..].iter().map(move |&second| (first, second))))
+        .collect();
+
+    candidates.into_iter()
+        .filter_map(|(first, second)| {
+            let distance = metric.distance(ids[first].as_ref(), ids[second].as_ref())?;
+            if distance <= max_distance {
+                Some(Similar { first, second, distance })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// The first ID that differs from a later one in exactly one position, and that one.
+fn find_box_ids(ids: &[String]) -> Option<(&str, &str)> {
+    near_duplicates(ids, 1).into_iter()
+        .find(|pair| pair.positions.len() == 1)
+        .map(|pair| (ids[pair.first].as_str(), ids[pair.second].as_str()))
+}
+
+pub struct Day02;
+impl Solution for Day02 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = Maybe<String>;
+
+    fn parse(contents: &str) -> Result<Vec<String>, ParseError> {
+        non_blank_lines(contents)
+            .map(|line| match line.fields()[..] {
+                [(_, id)] => Ok(id.to_string()),
+                _ => Err(line.error(0, line.text, "expected a single box ID")),
+            })
+            .collect()
+    }
+
+    // 7688
+    fn part1(ids: &Vec<String>) -> usize {
+        checksum(ids, &[2, 3])
+    }
+
+    // lsrivmotzbdxpkxnaqmuwcchj
+    fn part2(ids: &Vec<String>) -> Maybe<String> {
+        Maybe(find_box_ids(ids).map(|(a, b)| common_letters(a, b, Metric::Hamming)))
+    }
+}
+
+// Box IDs made of a few letters so some repeat, with one pair of IDs that
+// differ in exactly one position.
+impl Generate for Day02 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let letters: Vec<char> = "abcdefghij".chars().collect();
+        let mut ids: Vec<Vec<char>> = (0..size.max(2))
+            .map(|_| (0..12).map(|_| *rng.choose(&letters)).collect())
+            .collect();
+
+        let mut similar = ids[0].clone();
+        let position = rng.index(similar.len());
+        similar[position] = if similar[position] == 'z' { 'y' } else { 'z' };
+        ids[1] = similar;
+        rng.shuffle(&mut ids);
+
+        ids.iter().map(|id| id.iter().collect::<String>() + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{example, examples};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    // The checksum example explains every box ID after it, only keep the IDs.
+    fn box_ids() -> String {
+        example(SOURCE, "abcdef contains no letters that appear exactly two or three times.", 7)
+            .lines()
+            .map(|line| line.split(' ').next().unwrap())
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    examples! {
+        Day02,
+        checksum: box_ids() => part1: 12;
+        common_letters: example(SOURCE, "abcde", 7) => part2: "fgij";
+    }
+
+    #[test]
+    fn histogram_of_multiplicities() {
+        let ids = box_ids();
+        let ids: Vec<&str> = ids.lines().collect();
+        let histogram: Vec<(usize, usize)> = multiplicity_histogram(&ids).into_iter().collect();
+
+        assert_eq!(histogram, vec![(1, 6), (2, 4), (3, 3)]);
+        assert_eq!(super::checksum(&ids, &[2, 3]), 12);
+        assert_eq!(super::checksum(&ids, &[2, 3, 4]), 0);
+    }
+
+    #[test]
+    fn ids_within_a_distance() {
+        let ids = ["abcde", "axcye", "fghij", "fguij", "abcde", "klmno", "pqrst", "fgh", "fgx"];
+        let pairs: Vec<(usize, usize, Vec<usize>)> = near_duplicates(&ids, 2).into_iter()
+            .map(|pair| (pair.first, pair.second, pair.positions))
+            .collect();
+
+        assert_eq!(pairs, vec![
+            (0, 1, vec![1, 3]),
+            (0, 4, vec![]),
+            (1, 4, vec![1, 3]),
+            (2, 3, vec![2]),
+            (7, 8, vec![2]),
+        ]);
+        assert_eq!(near_duplicates(&ids, 0).len(), 1);
+    }
+
+    // Compares every pair, like the index should but much slower.
+    fn near_duplicates_of_every_pair(ids: &[String], max_distance: usize) -> Vec<NearDuplicate> {
+        let ids: Vec<Vec<char>> = ids.iter().map(|id| id.chars().collect()).collect();
+        let mut pairs = Vec::new();
+
+        for first in 0..ids.len() {
+            for second in first + 1..ids.len() {
+                if let Some(positions) = differences(&ids[first], &ids[second], max_distance) {
+                    pairs.push(NearDuplicate { first, second, positions });
+                }
+            }
+        }
+
+        pairs
+    }
+
+    #[test]
+    fn same_pairs_as_comparing_every_pair() {
+        use common::Rng;
+
+        let mut rng = Rng::new(7);
+        let ids: Vec<String> = (0..300).map(|_| (0..6).map(|_| *rng.choose(&['a', 'b', 'c'])).collect()).collect();
+
+        for max_distance in 0..4 {
+            assert_eq!(near_duplicates(&ids, max_distance), near_duplicates_of_every_pair(&ids, max_distance));
+        }
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(Metric::Hamming.distance("abcde", "axcye"), Some(2));
+        assert_eq!(Metric::Hamming.distance("abcde", "abcdef"), None);
+        assert_eq!(Metric::Levenshtein.distance("kitten", "sitting"), Some(3));
+        assert_eq!(Metric::Levenshtein.distance("fghij", "bacfghij"), Some(3));
+        assert_eq!(Metric::Levenshtein.distance("abcde", "bacde"), Some(2));
+        assert_eq!(Metric::Damerau.distance("abcde", "bacde"), Some(1));
+        assert_eq!(Metric::Damerau.distance("ca", "abc"), Some(3));
+    }
+
+    #[test]
+    fn aligned_common_letters() {
+        assert_eq!(super::common_letters("fghij", "fguij", Metric::Hamming), "fgij");
+        assert_eq!(super::common_letters("abcde", "bcdef", Metric::Hamming), "");
+        assert_eq!(super::common_letters("abcde", "bcdef", Metric::Levenshtein), "bcde");
+        assert_eq!(super::common_letters("abcde", "abxcde", Metric::Levenshtein), "abcde");
+        assert_eq!(super::common_letters("abcde", "bacde", Metric::Damerau), "cde");
+        assert_eq!(super::common_letters("kitten", "sitting", Metric::Damerau), "ittn");
+    }
+
+    #[test]
+    fn similar_ids_of_different_lengths() {
+        let ids = ["fghij", "fgij", "fguij", "abcde", "bacde", "abcdef"];
+        let pairs = |max, metric| -> Vec<(usize, usize, usize)> {
+            similar_ids(&ids, max, metric).into_iter().map(|pair| (pair.first, pair.second, pair.distance)).collect()
+        };
+
+        assert_eq!(pairs(1, Metric::Hamming), vec![(0, 2, 1)]);
+        assert_eq!(pairs(1, Metric::Levenshtein), vec![(0, 1, 1), (0, 2, 1), (1, 2, 1), (3, 5, 1)]);
+        assert_eq!(pairs(1, Metric::Damerau), vec![(0, 1, 1), (0, 2, 1), (1, 2, 1), (3, 4, 1), (3, 5, 1)]);
+        assert_eq!(pairs(2, Metric::Levenshtein).len(), 5);
+    }
+
+    #[test]
+    fn same_similar_ids_as_comparing_every_pair() {
+        use common::Rng;
+
+        let mut rng = Rng::new(11);
+        let ids: Vec<String> = (0..150)
+            .map(|_| (0..rng.range(3..=6)).map(|_| *rng.choose(&['a', 'b', 'c'])).collect())
+            .collect();
+
+        for &metric in &[Metric::Levenshtein, Metric::Damerau] {
+            for max_distance in 0..3 {
+                let mut every_pair = Vec::new();
+                for first in 0..ids.len() {
+                    for second in first + 1..ids.len() {
+                        let distance = metric.distance(&ids[first], &ids[second]).unwrap();
+                        if distance <= max_distance {
+                            every_pair.push(Similar { first, second, distance });
+                        }
+                    }
+                }
+
+                assert_eq!(similar_ids(&ids, max_distance, metric), every_pair, "{:?} {}", metric, max_distance);
+            }
+        }
+    }
+
+    #[test]
+    fn report_of_similar_ids() {
+        assert_eq!(side_by_side("abcde", "axcye", Metric::Hamming, false), "abcde\naxcye\n ^ ^\n");
+        assert_eq!(side_by_side("abcde", "abxcd", Metric::Levenshtein, false), "ab-cde\nabxcd-\n  ^  ^\n");
+        assert_eq!(side_by_side("ab", "ax", Metric::Hamming, true), "a\x1b[1;31mb\x1b[0m\na\x1b[1;31mx\x1b[0m\n");
+
+        let pairs = similar_ids(&["fghij", "abcde", "fguij", "xyz", "axcde", "fguzj", "xyy"], 1, Metric::Hamming);
+        assert_eq!(clusters(&pairs), vec![vec![0, 2, 5], vec![1, 4], vec![3, 6]]);
+    }
+
+    #[test]
+    fn letters_beyond_ascii() {
+        assert_eq!(letter_counts("ééa")[&'é'], 2);
+        assert_eq!(multiplicity_histogram(&["ééa", "ßßß"]).into_iter().collect::<Vec<_>>(), vec![(1, 1), (2, 1), (3, 1)]);
+    }
+}
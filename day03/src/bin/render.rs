@@ -0,0 +1,56 @@
+extern crate common;
+extern crate day03;
+
+use day03::{fabric_size, overlaps, ppm, render, Day03};
+use std::env;
+use std::fs;
+use std::process;
+
+const USAGE: &str = "Usage: render [<path>|-] [--ppm <path>] [--scale <n>]";
+
+// Fabrics wider than this are only drawn as an image.
+const MAX_TEXT_WIDTH: u64 = 200;
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+// Draws the claims to check both parts' answers by eye: as text like the
+// puzzle's pictures for small fabrics, or as a PPM image with the overlaps in
+// red and the intact claims in green.
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut path = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt").to_string();
+    let mut image = None;
+    let mut scale = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ppm" => image = Some(args.next().unwrap_or_else(|| fail(USAGE))),
+            "--scale" => scale = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0)
+                .unwrap_or_else(|| fail("--scale has to be a positive number")),
+            arg if arg.starts_with("--") => fail(USAGE),
+            _ => path = arg,
+        }
+    }
+
+    let claims = common::input_at::<Day03>(&path);
+    let (width, height) = fabric_size(&claims);
+    let too_large = format!("The fabric is {}x{} inches, too large to draw.", width, height);
+
+    match image {
+        Some(image) => {
+            let ppm = ppm(&claims, scale)
+                .unwrap_or_else(|| fail(&format!("The fabric is {}x{} inches, too large to draw at {} pixels to the inch.", width, height, scale)));
+            fs::write(&image, ppm)
+                .unwrap_or_else(|e| fail(&format!("Could not write {}: {}", image, e)));
+            println!("Drew {}x{} inches to {}.", width + 1, height + 1, image);
+        }
+        None if width <= MAX_TEXT_WIDTH => print!("{}", render(&claims).unwrap_or_else(|| fail(&too_large))),
+        None => fail(&format!("The fabric is {} inches wide, draw it with --ppm <path> instead.", width)),
+    }
+
+    let overlaps = overlaps(&claims);
+    println!("{} square inches overlap, intact claims: {:?}", overlaps.area, overlaps.intact);
+}
@@ -0,0 +1,713 @@
+// --- Day 3: No Matter How You Slice It ---
+// The Elves managed to locate the chimney-squeeze prototype fabric for Santa's suit (thanks to someone who helpfully wrote its box IDs on the wall of the warehouse in the middle of the night). Unfortunately, anomalies are still affecting them - nobody can even agree on how to cut the fabric.
+//
+// The whole piece of fabric they're working on is a very large square - at least 1000 inches on each side.
+//
+// Each Elf has made a claim about which area of fabric would be ideal for Santa's suit. All claims have an ID and consist of a single rectangle with edges parallel to the edges of the fabric. Each claim's rectangle is defined as follows:
+//
+// The number of inches between the left edge of the fabric and the left edge of the rectangle.
+// The number of inches between the top edge of the fabric and the top edge of the rectangle.
+// The width of the rectangle in inches.
+// The height of the rectangle in inches.
+// A claim like #123 @ 3,2: 5x4 means that claim ID 123 specifies a rectangle 3 inches from the left edge, 2 inches from the top edge, 5 inches wide, and 4 inches tall. Visually, it claims the square inches of fabric represented by # (and ignores the square inches of fabric represented by .) in the diagram below:
+//
+// ...........
+// ...........
+// ...#####...
+// ...#####...
+// ...#####...
+// ...#####...
+// ...........
+// ...........
+// ...........
+// The problem is that many of the claims overlap, causing two or more claims to cover part of the same areas. For example, consider the following claims:
+//
+// #1 @ 1,3: 4x4
+// #2 @ 3,1: 4x4
+// #3 @ 5,5: 2x2
+// Visually, these claim the following areas:
+//
+// ........
+// ...2222.
+// ...2222.
+// .11XX22.
+// .11XX22.
+// .111133.
+// .111133.
+// ........
+// The four square inches marked with X are claimed by both 1 and 2. (Claim 3, while adjacent to the others, does not overlap either of them.)
+//
+// If the Elves all proceed with their own plans, none of them will have enough fabric. How many square inches of fabric are within two or more claims?
+
+// --- Part Two ---
+// Amidst the chaos, you notice that exactly one claim doesn't overlap by even a single square inch of fabric with any other claim. If you can somehow draw attention to it, maybe the Elves will be able to make Santa's suit after all!
+//
+// For example, in the claims above, only claim 3 is intact after all claims are made.
+//
+// What is the ID of the only claim that doesn't overlap?
+//
+
+extern crate common;
+extern crate regex;
+
+use common::{non_blank_lines, Generate, Grid, Line, Maybe, ParseError, Rng, Solution};
+use regex::Match;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Claim {
+    pub id: u32,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+impl Claim {
+    // The left, top, right and bottom edges, the last two just outside the claim.
+    pub fn edges(self) -> (u64, u64, u64, u64) {
+        let (x, y) = (u64::from(self.x), u64::from(self.y));
+        (x, y, x + u64::from(self.width), y + u64::from(self.height))
+    }
+
+    fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+}
+
+fn to_u32(line: &Line, cap: Option<Match>) -> Result<u32, ParseError> {
+    let cap = cap.unwrap();
+    line.parse(cap.start(), cap.as_str(), "a number up to 4294967295")
+}
+
+// The width and height of the fabric the claims are on.
+pub fn fabric_size(claims: &[Claim]) -> (u64, u64) {
+    claims.iter().map(|c| c.edges()).fold((0, 0), |(width, height), (_, _, right, bottom)| {
+        (width.max(right), height.max(bottom))
+    })
+}
+
+// How many square inches are within two or more claims, and the ids of the
+// claims that don't overlap any other claim, in the order they were made.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Overlaps {
+    pub area: u64,
+    pub intact: Vec<u32>,
+}
+
+// Fabrics up to this many square inches are counted and drawn inch by inch,
+// larger ones can only be swept.
+const DENSE_LIMIT: u64 = 1 << 24;
+
+// Whether the fabric, with a row and a column to spare, is small enough to
+// keep every square inch of it.
+fn is_dense(claims: &[Claim]) -> bool {
+    let (width, height) = fabric_size(claims);
+    (width + 1).saturating_mul(height + 1) <= DENSE_LIMIT
+}
+
+pub fn overlaps(claims: &[Claim]) -> Overlaps {
+    if is_dense(claims) {
+        dense_overlaps(claims)
+    } else {
+        sweep_overlaps(claims)
+    }
+}
+
+// How many claims want each square inch of the fabric. Each claim only changes
+// the counts at its corners, and adding up the changes above and left of each
+// square inch gives its count.
+fn claimed(claims: &[Claim]) -> Grid<u32> {
+    let (width, height) = fabric_size(claims);
+    let (width, height) = (width as i32, height as i32);
+    let mut changes = Grid::new(0..width + 1, 0..height + 1, 0i32);
+
+    for c in claims {
+        let (left, top, right, bottom) = c.edges();
+        let (left, top, right, bottom) = (left as i32, top as i32, right as i32, bottom as i32);
+        changes[(left, top)] += 1;
+        changes[(right, top)] -= 1;
+        changes[(left, bottom)] -= 1;
+        changes[(right, bottom)] += 1;
+    }
+
+    let mut fabric = Grid::new(0..width, 0..height, 0);
+    for y in 0..height {
+        let mut row = 0;
+        for x in 0..width {
+            row += changes[(x, y)];
+            let above = if y > 0 { fabric[(x, y - 1)] as i32 } else { 0 };
+            fabric[(x, y)] = (above + row) as u32;
+        }
+    }
+
+    fabric
+}
+
+// Counts every square inch, then sums up the overlapping ones above and left
+// of every corner, so whether a claim overlaps anything takes four lookups.
+fn dense_overlaps(claims: &[Claim]) -> Overlaps {
+    let fabric = claimed(claims);
+    let (width, height) = (fabric.width() as i32, fabric.height() as i32);
+    let mut overlapping = Grid::new(0..width + 1, 0..height + 1, 0u64);
+
+    for y in 0..height {
+        for x in 0..width {
+            overlapping[(x + 1, y + 1)] = u64::from(fabric[(x, y)] > 1)
+                + overlapping[(x, y + 1)] + overlapping[(x + 1, y)] - overlapping[(x, y)];
+        }
+    }
+
+    let intact = claims.iter().filter(|c| {
+        let (left, top, right, bottom) = c.edges();
+        let (left, top, right, bottom) = (left as i32, top as i32, right as i32, bottom as i32);
+        overlapping[(right, bottom)] + overlapping[(left, top)]
+            == overlapping[(left, bottom)] + overlapping[(right, top)]
+    });
+
+    Overlaps {
+        area: overlapping[(width, height)],
+        intact: intact.map(|c| c.id).collect(),
+    }
+}
+
+// The distinct ys of the claims' top and bottom edges, and which gaps between
+// them each claim spans.
+fn gaps(claims: &[Claim]) -> (Vec<u64>, Vec<Range<usize>>) {
+    let mut ys: Vec<u64> = claims.iter()
+        .filter(|c| !c.is_empty())
+        .flat_map(|c| {
+            let (_, top, _, bottom) = c.edges();
+            vec![top, bottom]
+        })
+        .collect();
+    ys.sort();
+    ys.dedup();
+
+    let spans = claims.iter().map(|c| {
+        let (_, top, _, bottom) = c.edges();
+        ys.binary_search(&top).unwrap_or(0)..ys.binary_search(&bottom).unwrap_or(0)
+    }).collect();
+
+    (ys, spans)
+}
+
+// Where each claim starts and ends along the x axis, by index, from left to
+// right. Claims ending at an x come before the ones starting there, as they
+// don't touch.
+fn edge_events(claims: &[Claim]) -> Vec<(u64, bool, usize)> {
+    let mut events: Vec<_> = claims.iter().enumerate()
+        .filter(|(_, c)| !c.is_empty())
+        .flat_map(|(i, c)| {
+            let (left, _, right, _) = c.edges();
+            vec![(left, true, i), (right, false, i)]
+        })
+        .collect();
+    events.sort();
+    events
+}
+
+// The claims a vertical line through the fabric crosses, kept as a segment tree
+// over the gaps between the claims' top and bottom edges. A claim is counted at
+// the few nodes that make up its span of gaps and never pushed further down.
+struct Column {
+    ys: Vec<u64>,
+    // Claims spanning all of the node.
+    count: Vec<u32>,
+    // Length of the node within at least one and at least two claims.
+    once: Vec<u64>,
+    twice: Vec<u64>,
+    // The most claims at any one y within the node.
+    most: Vec<u32>,
+    // Claims not known to overlap any other claim yet, under their span's nodes.
+    intact: Vec<Vec<usize>>,
+}
+impl Column {
+    fn new(ys: Vec<u64>) -> Column {
+        let nodes = 4 * ys.len().max(1);
+        Column {
+            ys,
+            count: vec![0; nodes],
+            once: vec![0; nodes],
+            twice: vec![0; nodes],
+            most: vec![0; nodes],
+            intact: vec![Vec::new(); nodes],
+        }
+    }
+
+    fn gaps(&self) -> Range<usize> {
+        0..self.ys.len().saturating_sub(1)
+    }
+
+    fn twice(&self) -> u64 {
+        self.twice[1]
+    }
+
+    fn add(&mut self, span: &Range<usize>, change: i32) {
+        self.update(1, self.gaps(), span, change);
+    }
+
+    fn update(&mut self, node: usize, gaps: Range<usize>, span: &Range<usize>, change: i32) {
+        if span.start <= gaps.start && gaps.end <= span.end {
+            self.count[node] = (self.count[node] as i32 + change) as u32;
+        } else {
+            let middle = (gaps.start + gaps.end) / 2;
+            if span.start < middle {
+                self.update(2 * node, gaps.start..middle, span, change);
+            }
+            if middle < span.end {
+                self.update(2 * node + 1, middle..gaps.end, span, change);
+            }
+        }
+
+        let length = self.ys[gaps.end] - self.ys[gaps.start];
+        let (once, twice, most) = if gaps.len() == 1 {
+            (0, 0, 0)
+        } else {
+            let (left, right) = (2 * node, 2 * node + 1);
+            (self.once[left] + self.once[right], self.twice[left] + self.twice[right], self.most[left].max(self.most[right]))
+        };
+
+        self.once[node] = if self.count[node] > 0 { length } else { once };
+        self.twice[node] = match self.count[node] {
+            0 => twice,
+            1 => once,
+            _ => length,
+        };
+        self.most[node] = self.count[node] + most;
+    }
+
+    fn most(&self, span: &Range<usize>) -> u32 {
+        self.most_within(1, self.gaps(), span)
+    }
+
+    fn most_within(&self, node: usize, gaps: Range<usize>, span: &Range<usize>) -> u32 {
+        if span.start <= gaps.start && gaps.end <= span.end {
+            return self.most[node];
+        }
+
+        let middle = (gaps.start + gaps.end) / 2;
+        let mut most = 0;
+        if span.start < middle {
+            most = most.max(self.most_within(2 * node, gaps.start..middle, span));
+        }
+        if middle < span.end {
+            most = most.max(self.most_within(2 * node + 1, middle..gaps.end, span));
+        }
+
+        self.count[node] + most
+    }
+
+    fn file(&mut self, node: usize, gaps: Range<usize>, span: &Range<usize>, claim: usize) {
+        if span.start <= gaps.start && gaps.end <= span.end {
+            self.intact[node].push(claim);
+            return;
+        }
+
+        let middle = (gaps.start + gaps.end) / 2;
+        if span.start < middle {
+            self.file(2 * node, gaps.start..middle, span, claim);
+        }
+        if middle < span.end {
+            self.file(2 * node + 1, middle..gaps.end, span, claim);
+        }
+    }
+
+    // Takes out the claims filed on the way down to `gap`, which all span it.
+    // Some of them may have ended or overlapped something since.
+    fn take_spanning(&mut self, gap: usize) -> Vec<usize> {
+        let (mut node, mut gaps) = (1, self.gaps());
+        let mut claims = Vec::new();
+
+        loop {
+            claims.append(&mut self.intact[node]);
+            if gaps.len() == 1 {
+                return claims;
+            }
+
+            let middle = (gaps.start + gaps.end) / 2;
+            if gap < middle {
+                node *= 2;
+                gaps = gaps.start..middle;
+            } else {
+                node = 2 * node + 1;
+                gaps = middle..gaps.end;
+            }
+        }
+    }
+}
+
+// Sweeps a vertical line over the claims' left and right edges, so the work
+// only depends on the number of claims, no matter how far apart they are.
+//
+// A claim that starts overlaps the claims the line already crosses within its
+// span. The claims that were intact so far and overlap it are the ones spanning
+// its top gap and the ones starting within its span. Both are taken out of the
+// column once found, so each claim is only ever found once.
+pub fn sweep_overlaps(claims: &[Claim]) -> Overlaps {
+    let (ys, spans) = gaps(claims);
+    let mut column = Column::new(ys);
+    let mut intact = vec![true; claims.len()];
+    let mut crossing = vec![false; claims.len()];
+    // The intact claims the line crosses, by the gap their span starts at.
+    let mut starts = BTreeSet::new();
+    let (mut area, mut last) = (0, 0);
+
+    for (x, starting, i) in edge_events(claims) {
+        area += column.twice() * (x - last);
+        last = x;
+        let span = &spans[i];
+
+        if !starting {
+            column.add(span, -1);
+            crossing[i] = false;
+            starts.remove(&(span.start, i));
+            continue;
+        }
+
+        let mut overlapping = column.take_spanning(span.start);
+        overlapping.extend(starts.range((span.start, 0)..(span.end, 0)).map(|&(_, j)| j));
+        for j in overlapping {
+            if crossing[j] && intact[j] {
+                intact[j] = false;
+                starts.remove(&(spans[j].start, j));
+            }
+        }
+
+        if column.most(span) > 0 {
+            intact[i] = false;
+        } else {
+            column.file(1, column.gaps(), span, i);
+            starts.insert((span.start, i));
+        }
+        column.add(span, 1);
+        crossing[i] = true;
+    }
+
+    Overlaps {
+        area,
+        intact: claims.iter().zip(intact).filter(|&(_, intact)| intact).map(|(c, _)| c.id).collect(),
+    }
+}
+
+// Pairs of claims, by index, that share at least one square inch. Going
+// through the claims from left to right, each one only needs comparing with
+// the ones that start before it ends.
+fn overlapping_pairs(claims: &[Claim]) -> Vec<(usize, usize)> {
+    let edges: Vec<_> = claims.iter().map(|c| c.edges()).collect();
+    let mut order: Vec<usize> = (0..claims.len()).filter(|&i| !claims[i].is_empty()).collect();
+    order.sort_by_key(|&i| edges[i].0);
+    let mut pairs = Vec::new();
+
+    for (n, &i) in order.iter().enumerate() {
+        let (_, top, right, bottom) = edges[i];
+        for &j in order[n + 1..].iter().take_while(|&&j| edges[j].0 < right) {
+            let (_, other_top, _, other_bottom) = edges[j];
+            if top < other_bottom && other_top < bottom {
+                pairs.push((i.min(j), i.max(j)));
+            }
+        }
+    }
+
+    pairs.sort();
+    pairs
+}
+
+// The ids of the claims each claim overlaps, in the order of the claims.
+pub fn overlapping(claims: &[Claim]) -> Vec<Vec<u32>> {
+    let mut overlapping = vec![Vec::new(); claims.len()];
+
+    for (i, j) in overlapping_pairs(claims) {
+        overlapping[i].push(claims[j].id);
+        overlapping[j].push(claims[i].id);
+    }
+    for ids in &mut overlapping {
+        ids.sort();
+    }
+
+    overlapping
+}
+
+// The ids of the claims that overlap each other, directly or through other
+// claims, largest group first. Intact claims are groups of their own.
+pub fn components(claims: &[Claim]) -> Vec<Vec<u32>> {
+    let mut parents: Vec<usize> = (0..claims.len()).collect();
+
+    fn root(parents: &mut [usize], i: usize) -> usize {
+        if parents[i] == i {
+            return i;
+        }
+        let root = root(parents, parents[i]);
+        parents[i] = root;
+        root
+    }
+
+    for (i, j) in overlapping_pairs(claims) {
+        let (a, b) = (root(&mut parents, i), root(&mut parents, j));
+        parents[a.max(b)] = a.min(b);
+    }
+
+    let mut groups: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+    for (i, claim) in claims.iter().enumerate() {
+        let root = root(&mut parents, i);
+        groups.entry(root).or_default().push(claim.id);
+    }
+
+    let mut components: Vec<Vec<u32>> = groups.into_values().collect();
+    for component in &mut components {
+        component.sort();
+    }
+    components.sort_by_key(|component| (Reverse(component.len()), component[0]));
+    components
+}
+
+// How many square inches are within exactly how many claims, for every number
+// of claims some square inch is within. Sweeps over the claims' left and right
+// edges like `sweep_overlaps`, counting the claims over every gap as it goes.
+pub fn depths(claims: &[Claim]) -> BTreeMap<u32, u64> {
+    let (ys, spans) = gaps(claims);
+    let mut counts = vec![0u32; ys.len().saturating_sub(1)];
+    let mut depths = BTreeMap::new();
+    let mut last = 0;
+
+    for (x, starting, i) in edge_events(claims) {
+        if x > last {
+            for (gap, &count) in counts.iter().enumerate().filter(|&(_, &count)| count > 0) {
+                *depths.entry(count).or_insert(0) += (ys[gap + 1] - ys[gap]) * (x - last);
+            }
+            last = x;
+        }
+
+        for count in &mut counts[spans[i].clone()] {
+            if starting {
+                *count += 1;
+            } else {
+                *count -= 1;
+            }
+        }
+    }
+
+    depths
+}
+
+// What a square inch of the fabric shows in a picture of the claims.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Inch {
+    Free,
+    // By the index of the only claim that wants it.
+    Claimed(usize),
+    Overlapping,
+}
+
+// Every square inch of the fabric, with a row and a column of free fabric
+// around the claims' right and bottom like in the puzzle's pictures. Fabrics
+// too large to keep every square inch of can't be drawn.
+pub fn fabric(claims: &[Claim]) -> Option<Grid<Inch>> {
+    if !is_dense(claims) {
+        return None;
+    }
+
+    let (width, height) = fabric_size(claims);
+    let mut fabric = Grid::new(0..width as i32 + 1, 0..height as i32 + 1, Inch::Free);
+
+    for (i, c) in claims.iter().enumerate() {
+        let (left, top, right, bottom) = c.edges();
+        for y in top as i32..bottom as i32 {
+            for x in left as i32..right as i32 {
+                fabric[(x, y)] = match fabric[(x, y)] {
+                    Inch::Free => Inch::Claimed(i),
+                    _ => Inch::Overlapping,
+                };
+            }
+        }
+    }
+
+    Some(fabric)
+}
+
+// The fabric drawn like in the puzzle: each claimed square inch as the last
+// digit of its claim's id, overlapping ones as `X` and free ones as `.`.
+pub fn render(claims: &[Claim]) -> Option<String> {
+    Some(fabric(claims)?.render(|inch| match *inch {
+        Inch::Free => '.',
+        Inch::Claimed(i) => std::char::from_digit(claims[i].id % 10, 10).unwrap(),
+        Inch::Overlapping => 'X',
+    }))
+}
+
+// Images up to this many pixels are drawn, about 200MB of PPM.
+const MAX_PIXELS: usize = 1 << 26;
+
+// The fabric as a binary PPM image with `scale` pixels to the inch, unless that
+// makes too large an image. Overlapping square inches are red and intact claims
+// green, while every other claim gets a muted colour of its own.
+pub fn ppm(claims: &[Claim], scale: usize) -> Option<Vec<u8>> {
+    let fabric = fabric(claims)?;
+    let width = fabric.width().checked_mul(scale)?;
+    let height = fabric.height().checked_mul(scale)?;
+    if width.checked_mul(height)? > MAX_PIXELS {
+        return None;
+    }
+
+    let intact: Vec<bool> = overlapping(claims).iter().map(|ids| ids.is_empty()).collect();
+    let colours: Vec<[u8; 3]> = claims.iter().map(|c| {
+        let mut rng = Rng::new(u64::from(c.id));
+        [rng.range(60..=180) as u8, rng.range(60..=180) as u8, rng.range(60..=180) as u8]
+    }).collect();
+
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.reserve(width * height * 3);
+
+    for y in fabric.y_range() {
+        let row: Vec<u8> = fabric.row(y).iter().flat_map(|inch| {
+            let colour = match *inch {
+                Inch::Free => [24, 24, 24],
+                Inch::Claimed(i) if intact[i] => [40, 230, 40],
+                Inch::Claimed(i) => colours[i],
+                Inch::Overlapping => [230, 40, 40],
+            };
+            colour.iter().cloned().cycle().take(3 * scale).collect::<Vec<_>>()
+        }).collect();
+
+        for _ in 0..scale {
+            image.extend(&row);
+        }
+    }
+
+    Some(image)
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    type Input = Vec<Claim>;
+    type Part1 = u64;
+    type Part2 = Maybe<u32>;
+
+    fn parse(contents: &str) -> Result<Vec<Claim>, ParseError> {
+        let re = Regex::new(r"^\s*#(\d+) @ (\d+),(\d+): (\d+)x(\d+)\s*$").unwrap();
+
+        non_blank_lines(contents).map(|line| {
+            let cap = re.captures(line.text).ok_or_else(|| {
+                line.error(0, line.text, "expected a claim like #123 @ 3,2: 5x4")
+            })?;
+
+            Ok(Claim {
+                id: to_u32(&line, cap.get(1))?,
+                x: to_u32(&line, cap.get(2))?,
+                y: to_u32(&line, cap.get(3))?,
+                width: to_u32(&line, cap.get(4))?,
+                height: to_u32(&line, cap.get(5))?,
+            })
+        }).collect()
+    }
+
+    fn part1(claims: &Vec<Claim>) -> u64 {
+        overlaps(claims).area
+    }
+
+    fn part2(claims: &Vec<Claim>) -> Maybe<u32> {
+        Maybe(overlaps(claims).intact.first().cloned())
+    }
+}
+
+// The fabric grows with the number of claims, so they keep overlapping.
+impl Generate for Day03 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let fabric = (size as i32 * 4).clamp(20, 1000);
+
+        (1..=size.max(1)).map(|id| {
+            let (width, height) = (rng.range(1..=10), rng.range(1..=10));
+            let (x, y) = (rng.range(0..=fabric - width), rng.range(0..=fabric - height));
+            format!("#{} @ {},{}: {}x{}\n", id, x, y, width, height)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{example, examples, Generate, Rng, Solution};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    examples! {
+        Day03,
+        overlapping_claims: example(SOURCE, "#1 @ 1,3: 4x4", 3) => part1: 4, part2: 3;
+    }
+
+    #[test]
+    fn corrupted_claims() {
+        let error = Day03::parse("#1 @ 1,3: 4x4\n#2 @ 3,1 4x4\n").unwrap_err();
+        assert_eq!(error.to_string(), "2:1: expected a claim like #123 @ 3,2: 5x4, found `#2 @ 3,1 4x4`");
+
+        let error = Day03::parse("#1 @ 1,3: 4x4\n#2 @ 3,5000000000: 4x4\n").unwrap_err();
+        assert_eq!(error.to_string(), "2:8: expected a number up to 4294967295, found `5000000000`");
+    }
+
+    #[test]
+    fn sweep_agrees_with_counting() {
+        for seed in 0..10 {
+            for &size in &[5, 30, 150] {
+                let claims = Day03::parse(&Day03::generate(&mut Rng::new(seed), size)).unwrap();
+                assert_eq!(sweep_overlaps(&claims), dense_overlaps(&claims), "seed {}, size {}", seed, size);
+            }
+        }
+    }
+
+    #[test]
+    fn contention() {
+        let claims = Day03::parse("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n#4 @ 4,4: 2x2\n#5 @ 9,9: 1x1\n").unwrap();
+
+        assert_eq!(overlapping(&claims), vec![vec![2, 4], vec![1, 4], vec![4], vec![1, 2, 3], vec![]]);
+        assert_eq!(components(&claims), vec![vec![1, 2, 3, 4], vec![5]]);
+
+        let depths: Vec<(u32, u64)> = depths(&claims).into_iter().collect();
+        assert_eq!(depths, vec![(1, 26), (2, 6), (3, 1)]);
+    }
+
+    #[test]
+    fn contention_agrees_with_overlaps() {
+        for seed in 0..10 {
+            let claims = Day03::parse(&Day03::generate(&mut Rng::new(seed), 100)).unwrap();
+            let overlaps = overlaps(&claims);
+
+            let intact: Vec<u32> = claims.iter().zip(overlapping(&claims))
+                .filter(|(_, ids)| ids.is_empty())
+                .map(|(c, _)| c.id)
+                .collect();
+            assert_eq!(intact, overlaps.intact);
+            assert_eq!(depths(&claims).range(2..).map(|(_, &area)| area).sum::<u64>(), overlaps.area);
+            assert_eq!(components(&claims).iter().map(|c| c.len()).sum::<usize>(), claims.len());
+        }
+    }
+
+    #[test]
+    fn pictures() {
+        let claims = Day03::parse(&example(SOURCE, "#1 @ 1,3: 4x4", 3)).unwrap();
+        assert_eq!(render(&claims), Some(example(SOURCE, "........", 8) + "\n"));
+
+        let image = ppm(&claims, 2).unwrap();
+        let header = b"P6\n16 16\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 16 * 16 * 3);
+
+        // Claim 3 is intact and the overlap starts at 3,3, both two pixels to the inch.
+        let pixel = |x: usize, y: usize| &image[header.len() + (y * 16 + x) * 3..][..3];
+        assert_eq!(pixel(11, 11), [40, 230, 40]);
+        assert_eq!(pixel(6, 7), [230, 40, 40]);
+        assert_eq!(pixel(0, 0), [24, 24, 24]);
+
+        assert_eq!(ppm(&claims, 100_000), None);
+        assert_eq!(ppm(&claims, usize::MAX), None);
+    }
+
+    #[test]
+    fn huge_fabric() {
+        let claims = Day03::parse("#1 @ 4000000000,0: 3x3\n#2 @ 4000000002,2: 3x3\n#3 @ 0,4000000000: 2x2\n#4 @ 1,1: 0x5\n").unwrap();
+        assert_eq!(overlaps(&claims), Overlaps { area: 1, intact: vec![3, 4] });
+        assert_eq!(render(&claims), None);
+        assert_eq!(depths(&claims).into_iter().collect::<Vec<_>>(), vec![(1, 20), (2, 1)]);
+    }
+}
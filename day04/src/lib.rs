@@ -0,0 +1,245 @@
+// --- Day 4: Repose Record ---
+// You've sneaked into another supply closet - this time, it's across from the prototype suit manufacturing lab. You need to sneak inside and fix the issues with the suit, but there's a guard stationed outside the lab, so this is as close as you can safely get.
+//
+// As you search the closet for anything that might help, you discover that you're not the first person to want to sneak in. Covering the walls, someone has spent an hour starting every midnight for the past few months secretly observing this guard post! They've been writing down the ID of the one guard on duty that night - the Elves seem to have decided that one guard was enough for the overnight shift - as well as when they fall asleep or wake up while at their post (your puzzle input).
+//
+// For example, consider the following records, which have already been organized into chronological order:
+//
+// [1518-11-01 00:00] Guard #10 begins shift
+// [1518-11-01 00:05] falls asleep
+// [1518-11-01 00:25] wakes up
+// [1518-11-01 00:30] falls asleep
+// [1518-11-01 00:55] wakes up
+// [1518-11-01 23:58] Guard #99 begins shift
+// [1518-11-02 00:40] falls asleep
+// [1518-11-02 00:50] wakes up
+// [1518-11-03 00:05] Guard #10 begins shift
+// [1518-11-03 00:24] falls asleep
+// [1518-11-03 00:29] wakes up
+// [1518-11-04 00:02] Guard #99 begins shift
+// [1518-11-04 00:36] falls asleep
+// [1518-11-04 00:46] wakes up
+// [1518-11-05 00:03] Guard #99 begins shift
+// [1518-11-05 00:45] falls asleep
+// [1518-11-05 00:55] wakes up
+// Timestamps are written using year-month-day hour:minute format. The guard falling asleep or waking up is always the one whose shift most recently started. Because all asleep/awake times are during the midnight hour (00:00 - 00:59), only the minute portion (00 - 59) is relevant for those events.
+//
+// Visually, these records show that the guards are asleep at these times:
+//
+// Date   ID   Minute
+//             000000000011111111112222222222333333333344444444445555555555
+//             012345678901234567890123456789012345678901234567890123456789
+// 11-01  #10  .....####################.....#########################.....
+// 11-02  #99  ........................................##########..........
+// 11-03  #10  ........................#####...............................
+// 11-04  #99  ....................................##########..............
+// 11-05  #99  .............................................##########.....
+// The columns are Date, which shows the month-day portion of the relevant day; ID, which shows the guard on duty that day; and Minute, which shows the minutes during which the guard was asleep within the midnight hour. (The Minute column's header shows the minute's ten's digit in the first row and the one's digit in the second row.) Awake is shown as ., and asleep is shown as #.
+//
+// Note that guards count as asleep on the minute they fall asleep, and they count as awake on the minute they wake up. For example, because Guard #10 wakes up at 00:25 on 1518-11-01, minute 25 is marked as awake.
+//
+// If you can figure out the guard most likely to be asleep at a specific time, you might be able to trick that guard into working tonight so you can have the best chance of sneaking in. You have two strategies for choosing the best guard/minute combination.
+//
+// Strategy 1: Find the guard that has the most minutes asleep. What minute does that guard spend asleep the most?
+//
+// In the example above, Guard #10 spent the most minutes asleep, a total of 50 minutes (20+25+5), while Guard #99 only slept for a total of 30 minutes (10+10+10). Guard #10 was asleep most during minute 24 (on two days, whereas any other minute the guard was asleep was only seen on one day).
+//
+// While this example listed the entries in chronological order, your entries are in the order you found them. You'll need to organize them before they can be analyzed.
+//
+// What is the ID of the guard you chose multiplied by the minute you chose? (In the above example, the answer would be 10 * 24 = 240.)
+
+// --- Part Two ---
+// Strategy 2: Of all guards, which guard is most frequently asleep on the same minute?
+//
+// In the example above, Guard #99 spent minute 45 asleep more than any other guard or minute - three times in total. (In all other cases, any guard spent any minute asleep at most twice.)
+//
+// What is the ID of the guard you chose multiplied by the minute you chose? (In the above example, the answer would be 99 * 45 = 4455.)
+
+extern crate common;
+extern crate itertools;
+extern crate regex;
+
+use common::{non_blank_lines, Generate, Line, ParseError, Rng, Solution};
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct GuardAsleepRecord {
+    guard: String,
+    total_minutes: i32,
+    minutes: HashMap<i32, i32>,
+}
+impl GuardAsleepRecord {
+    fn update(&mut self, starts_sleep: i32, finishes_sleep: i32) {
+        self.total_minutes += finishes_sleep - starts_sleep;
+
+        for minute in starts_sleep..finishes_sleep {
+            let value = self.minutes.entry(minute).or_insert(0);
+            *value += 1;
+        }
+    }
+}
+
+// The guard and the minute they were chosen for, the answer is their product.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GuardMinute {
+    pub guard: i32,
+    pub minute: i32,
+}
+impl GuardMinute {
+    fn new((guard, minute): (&str, i32)) -> GuardMinute {
+        GuardMinute { guard: guard.parse().unwrap(), minute }
+    }
+}
+impl fmt::Display for GuardMinute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.guard * self.minute)
+    }
+}
+
+fn new_guard_asleep_record(guard: &str) -> GuardAsleepRecord {
+    GuardAsleepRecord {
+        guard: String::from(guard),
+        total_minutes: 0,
+        minutes: HashMap::new(),
+    }
+}
+
+fn how_long_do_guards_sleep(records: Vec<Line>) -> Result<HashMap<String, GuardAsleepRecord>, ParseError> {
+    let record_regex = Regex::new(
+        r"^\s*\[\d{4}-\d\d-\d\d \d\d:(\d\d)\] (?:Guard #(\d+) begins shift|(falls asleep)|wakes up)\s*$"
+    ).unwrap();
+    let mut guard = None;
+    let mut starts_sleep = None;
+    let mut acc = HashMap::new();
+
+    for record in records {
+        let cap = record_regex.captures(record.text).ok_or_else(|| {
+            record.error(0, record.text, "expected a record like [1518-11-01 00:00] Guard #10 begins shift")
+        })?;
+        let minutes = cap.get(1).unwrap().as_str().parse().unwrap();
+
+        if let Some(g) = cap.get(2) {
+            record.parse::<i32>(g.start(), g.as_str(), "a guard ID")?;
+            guard = Some(g.as_str());
+            starts_sleep = None;
+        } else if cap.get(3).is_some() {
+            if guard.is_none() {
+                return Err(record.error(0, record.text, "expected a guard to begin their shift before falling asleep"));
+            }
+            starts_sleep = Some(minutes);
+        } else {
+            match (guard, starts_sleep) {
+                (Some(guard), Some(starts_sleep)) if starts_sleep < minutes => {
+                    let r = acc.entry(guard.to_string()).or_insert_with(|| new_guard_asleep_record(guard));
+                    r.update(starts_sleep, minutes);
+                },
+                _ => return Err(record.error(0, record.text, "expected a guard to fall asleep before waking up")),
+            }
+            starts_sleep = None;
+        }
+    }
+
+    Ok(acc)
+}
+
+fn minute_for_record(r: &GuardAsleepRecord) -> i32 {
+    *r.minutes.iter().max_by_key(|x| x.1).unwrap().0
+}
+
+fn guard_and_minute_with_guard_most_asleep(asleep: &HashMap<String, GuardAsleepRecord>) -> (&str, i32) {
+    let record = &asleep.values().max_by_key(|x| x.total_minutes).unwrap();
+    (&record.guard, minute_for_record(record))
+}
+
+fn guard_and_minute_with_minute_most_asleep(asleep: &HashMap<String, GuardAsleepRecord>) -> (&str, i32) {
+    let record = &asleep.values().max_by_key(|x| x.minutes.values().max()).unwrap();
+    (&record.guard, minute_for_record(record))
+}
+
+pub struct Day04;
+impl Solution for Day04 {
+    type Input = HashMap<String, GuardAsleepRecord>;
+    type Part1 = GuardMinute;
+    type Part2 = GuardMinute;
+
+    fn parse(contents: &str) -> Result<HashMap<String, GuardAsleepRecord>, ParseError> {
+        // Using itertools/sorted here allows us to keep lines immutable by sorting on the iterator,
+        // the lines keep their numbers so errors still point at the right place in the input
+        let records = non_blank_lines(contents).sorted_by(|a, b| a.text.trim().cmp(b.text.trim()));
+        let asleep = how_long_do_guards_sleep(records)?;
+
+        if asleep.is_empty() {
+            return Err(ParseError::at_end(contents, "expected at least one guard to fall asleep"));
+        }
+
+        Ok(asleep)
+    }
+
+    fn part1(asleep: &HashMap<String, GuardAsleepRecord>) -> GuardMinute {
+        GuardMinute::new(guard_and_minute_with_guard_most_asleep(asleep))
+    }
+
+    fn part2(asleep: &HashMap<String, GuardAsleepRecord>) -> GuardMinute {
+        GuardMinute::new(guard_and_minute_with_minute_most_asleep(asleep))
+    }
+}
+
+// One shift per day and a handful of guards. The first shift always has a
+// nap, so someone falls asleep. The records come shuffled like the real ones.
+impl Generate for Day04 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let date = |day: usize| format!("{}-{:02}-{:02}", 1518 + day / 336, day / 28 % 12 + 1, day % 28 + 1);
+        let guards = (size / 4).max(1) as i32;
+        let mut records = Vec::new();
+
+        for day in 1..=size.max(1) {
+            let guard = rng.range(1..=guards) * 7;
+
+            if rng.chance(50) {
+                records.push(format!("[{} 23:{}] Guard #{} begins shift", date(day - 1), rng.range(45..=59), guard));
+            } else {
+                records.push(format!("[{} 00:{:02}] Guard #{} begins shift", date(day), rng.range(0..=5), guard));
+            }
+
+            let mut minute = rng.range(6..=20);
+            while minute < 58 && (rng.chance(60) || day == 1 && minute < 21) {
+                let wakes_up = rng.range(minute + 1..=59);
+                records.push(format!("[{} 00:{:02}] falls asleep", date(day), minute));
+                records.push(format!("[{} 00:{:02}] wakes up", date(day), wakes_up));
+                minute = wakes_up + 1;
+            }
+        }
+
+        rng.shuffle(&mut records);
+        records.iter().map(|r| r.clone() + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day04;
+    use common::{example, examples, Solution};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    examples! {
+        Day04,
+        records: example(SOURCE, "[1518-11-01 00:00] Guard #10 begins shift", 17) => part1: 240, part2: 4455;
+    }
+
+    #[test]
+    fn errors_point_at_the_unsorted_line() {
+        let records = "[1518-11-01 00:30] wakes up\n[1518-11-01 00:00] Guard #10 begins shift\n";
+        let error = Day04::parse(records).unwrap_err();
+        assert_eq!(error.to_string(), "1:1: expected a guard to fall asleep before waking up, found `[1518-11-01 00:30] wakes up`");
+    }
+
+    #[test]
+    fn errors_point_at_the_guard_id() {
+        let error = Day04::parse("  [1518-11-01 00:00] Guard #99999999999 begins shift\n").unwrap_err();
+        assert_eq!(error.to_string(), "1:29: expected a guard ID, found `99999999999`");
+    }
+}
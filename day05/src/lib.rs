@@ -0,0 +1,148 @@
+// --- Day 5: Alchemical Reduction ---
+// You've managed to sneak in to the prototype suit manufacturing lab. The Elves are making decent progress, but are still struggling with the suit's size reduction capabilities.
+//
+// While the very latest in 1518 alchemical technology might have solved their problem eventually, you can do better. You scan the chemical composition of the suit's material and discover that it is formed by extremely long polymers (one of which is available as your puzzle input).
+//
+// The polymer is formed by smaller units which, when triggered, react with each other such that two adjacent units of the same type and opposite polarity are destroyed. Units' types are represented by letters; units' polarity is represented by capitalization. For instance, r and R are units with the same type but opposite polarity, whereas r and s are entirely different types and do not react.
+//
+// For example:
+//
+// In aA, a and A react, leaving nothing behind.
+// In abBA, bB destroys itself, leaving aA. As above, this then destroys itself, leaving nothing.
+// In abAB, no two adjacent units are of the same type, and so nothing happens.
+// In aabAAB, even though aa and AA are of the same type, their polarities match, and so nothing happens.
+// Now, consider a larger example, dabAcCaCBAcCcaDA:
+//
+// dabAcCaCBAcCcaDA  The first 'cC' is removed.
+// dabAaCBAcCcaDA    This creates 'Aa', which is removed.
+// dabCBAcCcaDA      Either 'cC' or 'Cc' are removed (the result is the same).
+// dabCBAcaDA        No further actions can be taken.
+// After all possible reactions, the resulting polymer contains 10 units.
+//
+// How many units remain after fully reacting the polymer you scanned? (Note: in this puzzle and others, the input is large; if you copy/paste your input, make sure you get the whole thing.)
+
+// The first half of this puzzle is complete! It provides one gold star: *
+//
+// --- Part Two ---
+// Time to improve the polymer.
+//
+// One of the unit types is causing problems; it's preventing the polymer from collapsing as much as it should. Your goal is to figure out which unit type is causing the most problems, remove all instances of it (regardless of polarity), fully react the remaining polymer, and measure its length.
+//
+// For example, again using the polymer dabAcCaCBAcCcaDA from above:
+//
+// Removing all A/a units produces dbcCCBcCcD. Fully reacting this polymer produces dbCBcD, which has length 6.
+// Removing all B/b units produces daAcCaCAcCcaDA. Fully reacting this polymer produces daCAcaDA, which has length 8.
+// Removing all C/c units produces dabAaBAaDA. Fully reacting this polymer produces daDA, which has length 4.
+// Removing all D/d units produces abAcCaCBAcCcaA. Fully reacting this polymer produces abCBAc, which has length 6.
+// In this example, removing all C/c units was best, producing the answer 4.
+//
+// What is the length of the shortest polymer you can produce by removing all units of exactly one type and fully reacting the result?
+extern crate common;
+
+use common::{non_blank_lines, Generate, ParseError, Rng, Solution};
+
+fn react(reactant: &str) -> String {
+    let mut skip_next = false;
+
+    reactant.chars().enumerate().fold(String::from(""), |mut acc, c| {
+        if skip_next {
+            skip_next = false;
+        } else {
+            let chr = c.1;
+            let idx = c.0;
+
+            let next_character = match reactant.get(idx + 1..idx + 2) {
+                Some(nc) => nc.chars().next().unwrap(),
+                None => '🎄',
+            };
+
+            if (chr.is_lowercase() && next_character.is_uppercase() && chr == next_character.to_ascii_lowercase()) ||
+                (chr.is_uppercase() && next_character.is_lowercase() && chr == next_character.to_ascii_uppercase()) {
+                skip_next = true;
+            } else {
+                acc.push(chr);
+            }
+        }
+
+        acc
+    })
+}
+
+fn react_till_finished(mut reactant: String) -> String {
+    loop {
+        let product = react(&reactant);
+
+        if product.len() == reactant.len() {
+            return product;
+        }
+
+        reactant = product;
+    }
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(contents: &str) -> Result<String, ParseError> {
+        let mut lines = non_blank_lines(contents);
+        let line = lines.next().ok_or_else(|| ParseError::at_end(contents, "expected a polymer"))?;
+
+        if let Some(extra) = lines.next() {
+            return Err(extra.error(0, extra.text, "expected the polymer on a single line"));
+        }
+
+        let polymer = line.text.trim();
+        let start = line.text.find(polymer).unwrap();
+
+        match polymer.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            Some((i, c)) => Err(line.error(start + i, &c.to_string(), "expected a unit from a-z or A-Z")),
+            None => Ok(polymer.to_string()),
+        }
+    }
+
+    fn part1(reactant: &String) -> usize {
+        react_till_finished(reactant.to_string()).len()
+    }
+
+    fn part2(reactant: &String) -> usize {
+        let mut minimum_length = Self::part1(reactant);
+
+        ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'].iter().for_each(|c| {
+            let temporary_reactant = reactant.chars()
+                .filter(|x| x != c && *x != c.to_ascii_uppercase()).collect();
+            let temporary_product = react_till_finished(temporary_reactant);
+            if temporary_product.len() < minimum_length {
+                minimum_length = temporary_product.len();
+            }
+        });
+
+        minimum_length
+    }
+}
+
+// Only a few unit types, so plenty of them react.
+impl Generate for Day05 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let units: Vec<char> = "abcdeABCDE".chars().collect();
+        (0..size.max(1)).map(|_| *rng.choose(&units)).collect::<String>() + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day05;
+    use common::examples;
+
+    examples! {
+        Day05,
+        react_pair: "aA" => part1: 0;
+        react_nested: "abBA" => part1: 0;
+        no_same_type_adjacent: "abAB" => part1: 4;
+        same_polarity: "aabAAB" => part1: 6;
+        larger_example: "dabAcCaCBAcCcaDA" => part1: 10, part2: 4;
+    }
+}
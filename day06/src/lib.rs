@@ -0,0 +1,189 @@
+// --- Day 6: Chronal Coordinates ---
+// The device on your wrist beeps several times, and once again you feel like you're falling.
+//
+// "Situation critical," the device announces. "Destination indeterminate. Chronal interference detected. Please specify new target coordinates."
+//
+// The device then produces a list of coordinates (your puzzle input). Are they places it thinks are safe or dangerous? It recommends you check manual page 729. The Elves did not give you a manual.
+//
+// If they're dangerous, maybe you can minimize the danger by finding the coordinate that gives the largest distance from the other points.
+//
+// Using only the Manhattan distance, determine the area around each coordinate by counting the number of integer X,Y locations that are closest to that coordinate (and aren't tied in distance to any other coordinate).
+//
+// Your goal is to find the size of the largest area that isn't infinite. For example, consider the following list of coordinates:
+//
+// 1, 1
+// 1, 6
+// 8, 3
+// 3, 4
+// 5, 5
+// 8, 9
+// If we name these coordinates A through F, we can draw them on a grid, putting 0,0 at the top left:
+//
+// ..........
+// .A........
+// ..........
+// ........C.
+// ...D......
+// .....E....
+// .B........
+// ..........
+// ..........
+// ........F.
+// This view is partial - the actual grid extends infinitely in all directions. Using the Manhattan distance, each location's closest coordinate can be determined, shown here in lowercase:
+//
+// aaaaa.cccc
+// aAaaa.cccc
+// aaaddecccc
+// aadddeccCc
+// ..dDdeeccc
+// bb.deEeecc
+// bBb.eeee..
+// bbb.eeefff
+// bbb.eeffff
+// bbb.ffffFf
+// Locations shown as . are equally far from two or more coordinates, and so they don't count as being closest to any.
+//
+// In this example, the areas of coordinates A, B, C, and F are infinite - while not shown here, their areas extend forever outside the visible grid. However, the areas of coordinates D and E are finite: D is closest to 9 locations, and E is closest to 17 (both including the coordinate's location itself). Therefore, in this example, the size of the largest area is 17.
+//
+// What is the size of the largest area that isn't infinite?
+//
+//
+// --- Part Two ---
+// On the other hand, if the coordinates are safe, maybe the best you can do is try to find a region near as many coordinates as possible.
+//
+// For example, suppose you want the sum of the Manhattan distance to all of the coordinates to be less than 32. For each location, add up the distances to all of the given coordinates; if the total of those distances is less than 32, that location is within the desired region. Using the same coordinates as above, the resulting region looks like this:
+//
+// ..........
+// .A........
+// ..........
+// ...###..C.
+// ..#D###...
+// ..###E#...
+// .B.###....
+// ..........
+// ..........
+// ........F.
+// In particular, consider the highlighted location 4,3 located at the top middle of the region. Its calculation is as follows, where abs() is the absolute value function:
+//
+// Distance to coordinate A: abs(4-1) + abs(3-1) =  5
+// Distance to coordinate B: abs(4-1) + abs(3-6) =  6
+// Distance to coordinate C: abs(4-8) + abs(3-3) =  4
+// Distance to coordinate D: abs(4-3) + abs(3-4) =  2
+// Distance to coordinate E: abs(4-5) + abs(3-5) =  3
+// Distance to coordinate F: abs(4-8) + abs(3-9) = 10
+// Total distance: 5 + 6 + 4 + 2 + 3 + 10 = 30
+// Because the total distance to all coordinates (30) is less than 32, the location is within the region.
+//
+// This region, which also includes coordinates D and E, has a total size of 16.
+//
+// Your actual region will need to be much larger than this example, though, instead including all locations with a total distance of less than 10000.
+//
+// What is the size of the region containing all locations which have a total distance to all given coordinates of less than 10000?
+//
+
+extern crate common;
+
+use common::{non_blank_lines, BoundingBox, Generate, Grid, ParseError, Point, Rng, Solution};
+use std::collections::HashMap;
+
+// The grid has to include the coordinates furthest to the right and bottom,
+// otherwise their neighbours end up on the edge and count as infinite.
+fn grid_size(coordinates: &[Point]) -> (i32, i32) {
+    let max = BoundingBox::of(coordinates.iter().cloned()).unwrap().max;
+    (max.x + 1, max.y + 1)
+}
+
+// The only coordinate closest to x,y, none when several are equally close.
+fn closest(coordinates: &[Point], point: Point) -> Option<Point> {
+    let min_distance = coordinates.iter().map(|c| c.manhattan(point)).min()?;
+    let mut closest = coordinates.iter().filter(|c| c.manhattan(point) == min_distance);
+
+    match (closest.next(), closest.next()) {
+        (Some(coordinate), None) => Some(*coordinate),
+        _ => None,
+    }
+}
+
+fn central_area(coordinates: &[Point], max_total: i32) -> i32 {
+    let (max_x, max_y) = grid_size(coordinates);
+    let distances = Grid::from_fn(0..max_x, 0..max_y, |x, y| {
+        coordinates.iter().map(|c| c.manhattan(Point::new(x, y))).sum::<i32>()
+    });
+
+    distances.cells().filter(|(_, &total)| total < max_total).count() as i32
+}
+
+pub struct Day06;
+impl Solution for Day06 {
+    type Input = Vec<Point>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(contents: &str) -> Result<Vec<Point>, ParseError> {
+        let coordinates = non_blank_lines(contents)
+            .map(|line| match line.fields()[..] {
+                [(x_start, x), (y_start, y)] if x.ends_with(',') => {
+                    let x: u16 = line.parse(x_start, &x[..x.len() - 1], "a number up to 65535")?;
+                    let y: u16 = line.parse(y_start, y, "a number up to 65535")?;
+                    Ok(Point::new(i32::from(x), i32::from(y)))
+                },
+                _ => Err(line.error(0, line.text, "expected a coordinate like 1, 6")),
+            })
+            .collect::<Result<Vec<Point>, ParseError>>()?;
+
+        if coordinates.is_empty() {
+            return Err(ParseError::at_end(contents, "expected at least one coordinate"));
+        }
+
+        Ok(coordinates)
+    }
+
+    fn part1(coordinates: &Vec<Point>) -> i32 {
+        let (max_x, max_y) = grid_size(coordinates);
+        let grid = Grid::from_fn(0..max_x, 0..max_y, |x, y| closest(coordinates, Point::new(x, y)));
+
+        // Areas reaching the edge of the grid go on forever
+        let edge_coordinates: Vec<Point> = grid.edge().filter_map(|(_, c)| *c).collect();
+        let mut areas: HashMap<Point, i32> = HashMap::new();
+
+        for coordinate in grid.cells().filter_map(|(_, c)| *c) {
+            *areas.entry(coordinate).or_insert(0) += 1;
+        }
+
+        areas.iter()
+            .filter(|(c, _)| !edge_coordinates.contains(c))
+            .map(|(_, area)| *area)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn part2(coordinates: &Vec<Point>) -> i32 {
+        central_area(coordinates, 10000)
+    }
+}
+
+impl Generate for Day06 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let max = (size as i32 * 5).clamp(10, 400);
+        (0..size.max(1)).map(|_| format!("{}, {}\n", rng.range(0..=max), rng.range(0..=max))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{example, examples};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    examples! {
+        Day06,
+        largest_finite_area: example(SOURCE, "1, 1", 6) => part1: 17;
+    }
+
+    #[test]
+    fn region_with_total_distance_less_than_32() {
+        let coordinates = Day06::parse(&example(SOURCE, "1, 1", 6)).unwrap();
+        assert_eq!(central_area(&coordinates, 32), 16);
+    }
+}
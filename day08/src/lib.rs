@@ -0,0 +1,210 @@
+// --- Day 8: Memory Maneuver ---
+// The sleigh is much easier to pull than you'd expect for something its weight. Unfortunately, neither you nor the Elves know which way the North Pole is from here.
+//
+// You check your wrist device for anything that might help. It seems to have some kind of navigation system! Activating the navigation system produces more bad news: "Failed to start navigation system. Could not read software license file."
+//
+// The navigation system's license file consists of a list of numbers (your puzzle input). The numbers define a data structure which, when processed, produces some kind of tree that can be used to calculate the license number.
+//
+// The tree is made up of nodes; a single, outermost node forms the tree's root, and it contains all other nodes in the tree (or contains nodes that contain nodes, and so on).
+//
+// Specifically, a node consists of:
+//
+// A header, which is always exactly two numbers:
+// The quantity of child nodes.
+// The quantity of metadata entries.
+// Zero or more child nodes (as specified in the header).
+// One or more metadata entries (as specified in the header).
+// Each child node is itself a node that has its own header, child nodes, and metadata. For example:
+//
+// 2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2
+// A----------------------------------
+//     B----------- C-----------
+//                      D-----
+// In this example, each node of the tree is also marked with an underline starting with a letter for easier identification. In it, there are four nodes:
+//
+// A, which has 2 child nodes (B, C) and 3 metadata entries (1, 1, 2).
+// B, which has 0 child nodes and 3 metadata entries (10, 11, 12).
+// C, which has 1 child node (D) and 1 metadata entry (2).
+// D, which has 0 child nodes and 1 metadata entry (99).
+// The first check done on the license file is to simply add up all of the metadata entries. In this example, that sum is 1+1+2+10+11+12+2+99=138.
+//
+// What is the sum of all metadata entries?
+//
+// --- Part Two ---
+// The second check is slightly more complicated: you need to find the value of the root node (A in the example above).
+//
+// The value of a node depends on whether it has child nodes.
+//
+// If a node has no child nodes, its value is the sum of its metadata entries. So, the value of node B is 10+11+12=33, and the value of node D is 99.
+//
+// However, if a node does have child nodes, the metadata entries become indexes which refer to those child nodes. A metadata entry of 1 refers to the first child node, 2 to the second, 3 to the third, and so on. The value of this node is the sum of the values of the child nodes referenced by the metadata entries. If a referenced child node does not exist, that reference is skipped. A child node can be referenced multiple time and counts each time it is referenced. A metadata entry of 0 does not refer to any child node.
+//
+// For example, again using the above nodes:
+//
+// Node C has one metadata entry, 2. Because node C has only one child node, 2 references a child node which does not exist, and so the value of node C is 0.
+// Node A has three metadata entries: 1, 1, and 2. The 1 references node A's first child node, B, and the 2 references node A's second child node, C. Because node B has a value of 33 and node C has a value of 0, the value of node A is 33+33+0=66.
+// So, in this example, the value of the root node is 66.
+//
+// What is the value of the root node?
+
+extern crate common;
+
+use common::{lines, Generate, Line, ParseError, Rng, Solution};
+
+#[derive(Debug)]
+pub struct Node {
+    children: Vec<Node>,
+    metadata: Vec<i32>,
+}
+
+// The numbers of the license with the line and byte they were found at, so
+// a license that ends too early or goes on for too long can be pointed out.
+struct Numbers<'a> {
+    numbers: Vec<(Line<'a>, usize, i32)>,
+    next: usize,
+    end: ParseError,
+}
+impl<'a> Numbers<'a> {
+    fn new(contents: &'a str) -> Result<Numbers<'a>, ParseError> {
+        let mut numbers = Vec::new();
+
+        for line in lines(contents) {
+            for (start, field) in line.fields() {
+                let number: i32 = line.parse(start, field, "a number")?;
+
+                if number < 0 {
+                    return Err(line.error(start, field, "expected a number that isn't negative"));
+                }
+
+                numbers.push((line, start, number));
+            }
+        }
+
+        Ok(Numbers {
+            numbers,
+            next: 0,
+            end: ParseError::at_end(contents, "expected more numbers, the license ends in the middle of a node"),
+        })
+    }
+
+    fn next(&mut self) -> Result<i32, ParseError> {
+        let number = self.numbers.get(self.next).map(|n| n.2).ok_or_else(|| self.end.clone())?;
+        self.next += 1;
+        Ok(number)
+    }
+}
+
+fn parse_nodes(numbers: &mut Numbers) -> Result<Node, ParseError> {
+    let number_of_children = numbers.next()?;
+    let number_of_metadata = numbers.next()?;
+
+    Ok(Node {
+        children: (0..number_of_children).map(|_| parse_nodes(numbers)).collect::<Result<_, _>>()?,
+        metadata: (0..number_of_metadata).map(|_| numbers.next()).collect::<Result<_, _>>()?,
+    })
+}
+
+fn fetch_metadata(node: &Node) -> Vec<i32> {
+    let mut metadata = node.metadata.clone();
+
+    node.children.iter().for_each(|c| {
+        metadata.append(&mut fetch_metadata(c));
+    });
+
+    metadata
+}
+
+fn fetch_node_value(node: &Node) -> i32 {
+    if node.children.is_empty() {
+        node.metadata.iter().sum()
+    } else {
+        node.metadata.iter().map(|m| {
+            if *m == 0 {
+                0
+            } else if node.children.len() as i32 >= *m {
+                fetch_node_value(node.children.get((*m-1) as usize).unwrap())
+            } else {
+                0
+            }
+        }).sum()
+    }
+}
+
+pub struct Day08;
+impl Solution for Day08 {
+    type Input = Node;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(contents: &str) -> Result<Node, ParseError> {
+        let mut numbers = Numbers::new(contents)?;
+        let node = parse_nodes(&mut numbers)?;
+
+        if let Some(&(line, start, number)) = numbers.numbers.get(numbers.next) {
+            return Err(line.error(start, &number.to_string(), "expected the license to end after the root node"));
+        }
+
+        Ok(node)
+    }
+
+    fn part1(node: &Node) -> i32 {
+        fetch_metadata(node).iter().sum()
+    }
+
+    fn part2(node: &Node) -> i32 {
+        fetch_node_value(node)
+    }
+}
+
+fn generate_node(rng: &mut Rng, nodes: &mut usize, depth: usize, numbers: &mut Vec<String>) {
+    let children = if depth > 40 || *nodes == 0 { 0 } else { rng.range(0..=3) as usize };
+    let metadata = rng.range(1..=3);
+    numbers.push(children.to_string());
+    numbers.push(metadata.to_string());
+
+    for _ in 0..children {
+        if *nodes > 0 {
+            *nodes -= 1;
+        }
+        generate_node(rng, nodes, depth + 1, numbers);
+    }
+
+    for _ in 0..metadata {
+        numbers.push(rng.range(0..=children as i32 + 1).to_string());
+    }
+}
+
+// Roughly `size` nodes, with metadata that sometimes points at no child.
+impl Generate for Day08 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut nodes = size;
+        let mut numbers = Vec::new();
+        generate_node(rng, &mut nodes, 0, &mut numbers);
+        numbers.join(" ") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day08;
+    use common::{example, examples, Solution};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    examples! {
+        Day08,
+        license: example(SOURCE, "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2", 1) => part1: 138, part2: 66;
+    }
+
+    #[test]
+    fn truncated_license() {
+        let error = Day08::parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1").unwrap_err();
+        assert_eq!(error.to_string(), "1:32: expected more numbers, the license ends in the middle of a node");
+    }
+
+    #[test]
+    fn license_with_numbers_after_the_root_node() {
+        let error = Day08::parse("0 1 99\n7\n").unwrap_err();
+        assert_eq!(error.to_string(), "2:1: expected the license to end after the root node, found `7`");
+    }
+}
@@ -0,0 +1,317 @@
+// --- Day 10: The Stars Align ---
+// It's no use; your navigation system simply isn't capable of providing walking directions in the arctic circle, and certainly not in 1018.
+//
+// The Elves suggest an alternative. In times like these, North Pole rescue operations will arrange points of light in the sky to guide missing Elves back to base. Unfortunately, the message is easy to miss: the points move slowly enough that it takes hours to align them, but have so much momentum that they only stay aligned for a second. If you blink at the wrong time, it might be hours before another message appears.
+//
+// You can see these points of light floating in the distance, and record their position in the sky and their velocity, the relative change in position per second (your puzzle input). The coordinates are all given from your perspective; given enough time, those positions and velocities will move the points into a cohesive message!
+//
+// Rather than wait, you decide to fast-forward the process and calculate what the points will eventually spell.
+//
+// For example, suppose you note the following points:
+//
+// position=< 9,  1> velocity=< 0,  2>
+// position=< 7,  0> velocity=<-1,  0>
+// position=< 3, -2> velocity=<-1,  1>
+// position=< 6, 10> velocity=<-2, -1>
+// position=< 2, -4> velocity=< 2,  2>
+// position=<-6, 10> velocity=< 2, -2>
+// position=< 1,  8> velocity=< 1, -1>
+// position=< 1,  7> velocity=< 1,  0>
+// position=<-3, 11> velocity=< 1, -2>
+// position=< 7,  6> velocity=<-1, -1>
+// position=<-2,  3> velocity=< 1,  0>
+// position=<-4,  3> velocity=< 2,  0>
+// position=<10, -3> velocity=<-1,  1>
+// position=< 5, 11> velocity=< 1, -2>
+// position=< 4,  7> velocity=< 0, -1>
+// position=< 8, -2> velocity=< 0,  1>
+// position=<15,  0> velocity=<-2,  0>
+// position=< 1,  6> velocity=< 1,  0>
+// position=< 8,  9> velocity=< 0, -1>
+// position=< 3,  3> velocity=<-1,  1>
+// position=< 0,  5> velocity=< 0, -1>
+// position=<-2,  2> velocity=< 2,  0>
+// position=< 5, -2> velocity=< 1,  2>
+// position=< 1,  4> velocity=< 2,  1>
+// position=<-2,  7> velocity=< 2, -2>
+// position=< 3,  6> velocity=<-1, -1>
+// position=< 5,  0> velocity=< 1,  0>
+// position=<-6,  0> velocity=< 2,  0>
+// position=< 5,  9> velocity=< 1, -2>
+// position=<14,  7> velocity=<-2,  0>
+// position=<-3,  6> velocity=< 2, -1>
+// Each line represents one point. Positions are given as <X, Y> pairs: X represents how far left (negative) or right (positive) the point appears, while Y represents how far up (negative) or down (positive) the point appears.
+//
+// At 0 seconds, each point has the position given. Each second, each point's velocity is added to its position. So, a point with velocity <1, -2> is moving to the right, but is moving upward twice as quickly. If this point's initial position were <3, 9>, after 3 seconds, its position would become <6, 3>.
+//
+// Over time, the points listed above would move like this:
+//
+// Initially:
+// ........#.............
+// ................#.....
+// .........#.#..#.......
+// ......................
+// #..........#.#.......#
+// ...............#......
+// ....#.................
+// ..#.#....#............
+// .......#..............
+// ......#...............
+// ...#...#.#...#........
+// ....#..#..#.........#.
+// .......#..............
+// ...........#..#.......
+// #...........#.........
+// ...#.......#..........
+//
+// After 1 second:
+// ......................
+// ......................
+// ..........#....#......
+// ........#.....#.......
+// ..#.........#......#..
+// ......................
+// ......#...............
+// ....##.........#......
+// ......#.#.............
+// .....##.##..#.........
+// ........#.#...........
+// ........#...#.....#...
+// ..#...........#.......
+// ....#.....#.#.........
+// ......................
+// ......................
+//
+// After 2 seconds:
+// ......................
+// ......................
+// ......................
+// ..............#.......
+// ....#..#...####..#....
+// ......................
+// ........#....#........
+// ......#.#.............
+// .......#...#..........
+// .......#..#..#.#......
+// ....#....#.#..........
+// .....#...#...##.#.....
+// ........#.............
+// ......................
+// ......................
+// ......................
+//
+// After 3 seconds:
+// ......................
+// ......................
+// ......................
+// ......................
+// ......#...#..###......
+// ......#...#...#.......
+// ......#...#...#.......
+// ......#####...#.......
+// ......#...#...#.......
+// ......#...#...#.......
+// ......#...#...#.......
+// ......#...#..###......
+// ......................
+// ......................
+// ......................
+// ......................
+//
+// After 4 seconds:
+// ......................
+// ......................
+// ......................
+// ............#.........
+// ........##...#.#......
+// ......#.....#..#......
+// .....#..##.##.#.......
+// .......##.#....#......
+// ...........#....#.....
+// ..............#.......
+// ....#......#...#......
+// .....#.....##.........
+// ...............#......
+// ...............#......
+// ......................
+// ......................
+// After 3 seconds, the message appeared briefly: HI. Of course, your message will be much longer and will take many more seconds to appear.
+//
+// What message will eventually appear in the sky?
+//
+// Your puzzle answer was RGRKHKNA.
+//
+// The first half of this puzzle is complete! It provides one gold star: *
+//
+// --- Part Two ---
+// Good thing you didn't have to wait, because that would have taken a long time - much longer than the 3 seconds in the example above.
+//
+// Impressed by your sub-hour communication capabilities, the Elves are curious: exactly how many seconds would they have needed to wait for that message to appear?
+
+extern crate common;
+extern crate regex;
+
+use common::{non_blank_lines, BoundingBox, Generate, Grid, Line, ParseError, Point, Rng, Solution, Vector};
+use regex::Match;
+use std::fmt;
+use regex::Regex;
+
+#[derive(Clone, Debug)]
+pub struct PointWithVelocity {
+    position: Point,
+    velocity: Vector,
+}
+
+// The points of light drawn at the second they align, '#' marks a point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message(pub String);
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn to_i32(line: &Line, cap: Option<Match>) -> Result<i32, ParseError> {
+    let cap = cap.unwrap();
+    line.parse(cap.start(), cap.as_str(), "a number")
+}
+
+fn move_points(points: &mut [PointWithVelocity]) {
+    for point in points {
+        point.position += point.velocity;
+    }
+}
+
+fn bounds(points: &[PointWithVelocity]) -> BoundingBox {
+    BoundingBox::of(points.iter().map(|p| p.position)).unwrap()
+}
+
+fn close_enough(points: &[PointWithVelocity]) -> bool {
+    let bounds = bounds(points);
+    bounds.width() <= 150 && bounds.height() <= 150
+}
+
+fn draw(points: &[PointWithVelocity]) -> String {
+    let bounds = bounds(points);
+    let mut sky = Grid::new(bounds.x_range(), bounds.y_range(), '.');
+
+    for point in points {
+        sky[point.position] = '#';
+    }
+
+    sky.render(|&c| c)
+}
+
+fn align(points: &[PointWithVelocity]) -> (Vec<PointWithVelocity>, i32) {
+    let mut points = points.to_vec();
+    let mut seconds = 0;
+    let mut abs_min_width = 10000;
+    let mut cache: Vec<PointWithVelocity> = points.clone();
+
+    while !close_enough(&points) {
+        seconds += 1;
+        move_points(&mut points);
+    }
+
+    loop {
+        let width = bounds(&points).width();
+
+        if width < abs_min_width {
+            abs_min_width = width;
+        } else {
+            return (cache, seconds - 1);
+        }
+
+        cache = points.clone();
+        seconds += 1;
+        move_points(&mut points);
+    }
+}
+
+pub struct Day10;
+impl Solution for Day10 {
+    type Input = Vec<PointWithVelocity>;
+    type Part1 = Message;
+    type Part2 = i32;
+
+    fn parse(contents: &str) -> Result<Vec<PointWithVelocity>, ParseError> {
+        let re = Regex::new(r"^\s*position=<\s*(-?\d+),\s*(-?\d+)> velocity=<\s*(-?\d+),\s*(-?\d+)>\s*$").unwrap();
+
+        let points = non_blank_lines(contents).map(|line| {
+            let cap = re.captures(line.text).ok_or_else(|| {
+                line.error(0, line.text, "expected a point like position=< 9,  1> velocity=< 0,  2>")
+            })?;
+
+            Ok(PointWithVelocity {
+                position: Point::new(to_i32(&line, cap.get(1))?, to_i32(&line, cap.get(2))?),
+                velocity: Vector::new(to_i32(&line, cap.get(3))?, to_i32(&line, cap.get(4))?),
+            })
+        }).collect::<Result<Vec<PointWithVelocity>, ParseError>>()?;
+
+        if points.is_empty() {
+            return Err(ParseError::at_end(contents, "expected at least one point"));
+        }
+
+        Ok(points)
+    }
+
+    // RGRKHKNA
+    fn part1(points: &Vec<PointWithVelocity>) -> Message {
+        let (aligned, _) = align(points);
+        Message(draw(&aligned))
+    }
+
+    fn part2(points: &Vec<PointWithVelocity>) -> i32 {
+        let (_, seconds) = align(points);
+        seconds
+    }
+}
+
+// A random message of `size` columns that the points spell after a random
+// number of seconds. Both outermost columns have points moving left and right,
+// so the message is smaller than the sky a second before and after.
+impl Generate for Day10 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = (size as i32).clamp(4, 100);
+        let seconds = rng.range(1..=100);
+        let mut message: Vec<Point> = (0..width)
+            .flat_map(|x| (0..8).map(move |y| Point::new(x, y)))
+            .filter(|_| rng.chance(30))
+            .collect();
+        message.extend(vec![Point::new(0, 0), Point::new(0, 7), Point::new(width - 1, 0), Point::new(width - 1, 7)]);
+
+        message.iter().enumerate().map(|(i, &point)| {
+            let mut velocity = Vector::new(rng.range(-3..=3), rng.range(-3..=3));
+            if i >= message.len() - 4 {
+                velocity.x = if i % 2 == 0 { 2 } else { -2 };
+            }
+
+            let start = point + velocity * -seconds;
+            format!("position=<{}, {}> velocity=<{}, {}>\n", start.x, start.y, velocity.x, velocity.y)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use common::{example, examples};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    const HI: &str = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+";
+
+    examples! {
+        Day10,
+        message: example(SOURCE, "position=< 9,  1> velocity=< 0,  2>", 31) => part1: HI, part2: 3;
+    }
+}
@@ -0,0 +1,508 @@
+// --- Day 13: Mine Cart Madness ---
+// A crop of this size requires significant logistics to transport produce, soil, fertilizer, and so on. The Elves are very busy pushing things around in carts on some kind of rudimentary system of tracks they've come up with.
+//
+// Seeing as how cart-and-track systems don't appear in recorded history for another 1000 years, the Elves seem to be making this up as they go along. They haven't even figured out how to avoid collisions yet.
+//
+// You map out the tracks (your puzzle input) and see where you can help.
+//
+// Tracks consist of straight paths (| and -), curves (/ and \), and intersections (+). Curves connect exactly two perpendicular pieces of track; for example, this is a closed loop:
+//
+// /----\
+// |    |
+// |    |
+// \----/
+//
+// Intersections occur when two perpendicular paths cross. At an intersection, a cart is capable of turning left, turning right, or continuing straight. Here are two loops connected by two intersections:
+//
+// /-----\
+// |     |
+// |  /--+--\
+// |  |  |  |
+// \--+--/  |
+//    |     |
+//    \-----/
+//
+// Several carts are also on the tracks. Carts always face either up (^), down (v), left (<), or right (>). (On your initial map, the track under each cart is a straight path matching the direction the cart is facing.)
+//
+// Each time a cart has the option to turn (by arriving at any intersection), it turns left the first time, goes straight the second time, turns right the third time, and then repeats those directions starting again with left the fourth time, straight the fifth time, and so on. This process is independent of the particular intersection at which the cart has arrived - that is, the cart has no per-intersection memory.
+//
+// Carts all move at the same speed; they take turns moving a single step at a time. They do this based on their current location: carts on the top row move first (acting from left to right), then carts on the second row move (again from left to right), then carts on the third row, and so on. Once each cart has moved one step, the process repeats; each of these loops is called a tick.
+//
+// For example, suppose there are two carts on a straight track:
+//
+// |  |  |  |  |
+// v  |  |  |  |
+// |  v  v  |  |
+// |  |  |  v  X
+// |  |  ^  ^  |
+// ^  ^  |  |  |
+// |  |  |  |  |
+//
+// First, the top cart moves. It is facing down (v), so it moves down one square. Second, the bottom cart moves. It is facing up (^), so it moves up one square. Because all carts have moved, the first tick ends. Then, the process repeats, starting with the first cart. The first cart moves down, then the second cart moves up - right into the first cart, colliding with it! (The location of the crash is marked with an X.) This ends the second and last tick.
+//
+// Here is a longer example:
+//
+// /->-\
+// |   |  /----\
+// | /-+--+-\  |
+// | | |  | v  |
+// \-+-/  \-+--/
+//   \------/
+//
+// /-->\
+// |   |  /----\
+// | /-+--+-\  |
+// | | |  | |  |
+// \-+-/  \->--/
+//   \------/
+//
+// /---v
+// |   |  /----\
+// | /-+--+-\  |
+// | | |  | |  |
+// \-+-/  \-+>-/
+//   \------/
+//
+// /---\
+// |   v  /----\
+// | /-+--+-\  |
+// | | |  | |  |
+// \-+-/  \-+->/
+//   \------/
+//
+// /---\
+// |   |  /----\
+// | /->--+-\  |
+// | | |  | |  |
+// \-+-/  \-+--^
+//   \------/
+//
+// /---\
+// |   |  /----\
+// | /-+>-+-\  |
+// | | |  | |  ^
+// \-+-/  \-+--/
+//   \------/
+//
+// /---\
+// |   |  /----\
+// | /-+->+-\  ^
+// | | |  | |  |
+// \-+-/  \-+--/
+//   \------/
+//
+// /---\
+// |   |  /----<
+// | /-+-->-\  |
+// | | |  | |  |
+// \-+-/  \-+--/
+//   \------/
+//
+// /---\
+// |   |  /---<\
+// | /-+--+>\  |
+// | | |  | |  |
+// \-+-/  \-+--/
+//   \------/
+//
+// /---\
+// |   |  /--<-\
+// | /-+--+-v  |
+// | | |  | |  |
+// \-+-/  \-+--/
+//   \------/
+//
+// /---\
+// |   |  /-<--\
+// | /-+--+-\  |
+// | | |  | v  |
+// \-+-/  \-+--/
+//   \------/
+//
+// /---\
+// |   |  /<---\
+// | /-+--+-\  |
+// | | |  | |  |
+// \-+-/  \-<--/
+//   \------/
+//
+// /---\
+// |   |  v----\
+// | /-+--+-\  |
+// | | |  | |  |
+// \-+-/  \<+--/
+//   \------/
+//
+// /---\
+// |   |  /----\
+// | /-+--v-\  |
+// | | |  | |  |
+// \-+-/  ^-+--/
+//   \------/
+//
+// /---\
+// |   |  /----\
+// | /-+--+-\  |
+// | | |  X |  |
+// \-+-/  \-+--/
+//   \------/
+//
+// After following their respective paths for a while, the carts eventually crash. To help prevent crashes, you'd like to know the location of the first crash. Locations are given in X,Y coordinates, where the furthest left column is X=0 and the furthest top row is Y=0:
+//
+//            111
+//  0123456789012
+// 0/---\
+// 1|   |  /----\
+// 2| /-+--+-\  |
+// 3| | |  X |  |
+// 4\-+-/  \-+--/
+// 5  \------/
+//
+// In this example, the location of the first crash is 7,3.
+//
+// --- Part Two ---
+//
+// There isn't much you can do to prevent crashes in this ridiculous system. However, by predicting the crashes, the Elves know where to be in advance and instantly remove the two crashing carts the moment any crash occurs.
+//
+// They can proceed like this for a while, but eventually, they're going to run out of carts. It could be useful to figure out where the last cart that hasn't crashed will end up.
+//
+// For example:
+//
+// />-<\
+// |   |
+// | /<+-\
+// | | | v
+// \>+</ |
+//   |   ^
+//   \<->/
+//
+// /---\
+// |   |
+// | v-+-\
+// | | | |
+// \-+-/ |
+//   |   |
+//   ^---^
+//
+// /---\
+// |   |
+// | /-+-\
+// | v | |
+// \-+-/ |
+//   ^   ^
+//   \---/
+//
+// /---\
+// |   |
+// | /-+-\
+// | | | |
+// \-+-/ ^
+//   |   |
+//   \---/
+//
+// After four very expensive crashes, a tick ends with only one cart remaining; its final location is 6,4.
+//
+// What is the location of the last cart at the end of the first tick where it is the only cart left?
+
+use common::{lines, Direction, Generate, Grid, Maybe, ParseError, Point, Rng, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+enum TurnedTowards {
+    Left,
+    Straight,
+    Right,
+}
+
+#[derive(Clone, Debug)]
+struct Cart {
+    id: String,
+    position: Point,
+    direction: Direction,
+    turned_towards_last: TurnedTowards,
+}
+impl Cart {
+    fn new(position: Point, direction: Direction) -> Cart {
+        Cart {
+            id: position.to_string(),
+            position,
+            direction,
+            turned_towards_last: TurnedTowards::Right
+        }
+    }
+    fn change_direction(&mut self) {
+        match self.turned_towards_last {
+            TurnedTowards::Left => {
+                self.turned_towards_last = TurnedTowards::Straight;
+                self.walk();
+            },
+            TurnedTowards::Straight => {
+                self.turned_towards_last = TurnedTowards::Right;
+                self.turn_right();
+            },
+            TurnedTowards::Right => {
+                self.turned_towards_last = TurnedTowards::Left;
+                self.turn_left();
+            },
+        }
+    }
+    fn turn_left(&mut self) {
+        self.direction = self.direction.turn_left();
+        self.walk();
+    }
+    fn turn_right(&mut self) {
+        self.direction = self.direction.turn_right();
+        self.walk();
+    }
+    fn walk(&mut self) {
+        self.position += self.direction.vector();
+    }
+}
+impl Eq for Cart {}
+impl PartialEq for Cart {
+    fn eq(&self, other: &Cart) -> bool {
+        self.id == other.id
+    }
+}
+
+fn collect_carts(map: &Grid<char>) -> Vec<Cart> {
+    let mut carts: Vec<Cart> = Vec::new();
+
+    for ((x, y), position) in map.cells() {
+        let direction = match position {
+            '^' => Direction::Up,
+            'v' => Direction::Down,
+            '<' => Direction::Left,
+            '>' => Direction::Right,
+            _ => continue,
+        };
+        carts.push(Cart::new(Point::new(x, y), direction));
+    }
+
+    carts
+}
+
+// Whether the track goes on both ways the cart faces, since carts start out on
+// straight track.
+fn on_track(map: &Grid<char>, cart: &Cart) -> bool {
+    let connecting = if cart.direction.horizontal() { "-+/\\<>" } else { "|+/\\^v" };
+
+    [cart.direction, cart.direction.reverse()].iter().all(|direction| {
+        let next = cart.position + direction.vector();
+        map.get(next.x, next.y).is_some_and(|track| connecting.contains(*track))
+    })
+}
+
+// Whether the cart keeps driving on track. Where a cart goes doesn't depend on
+// the other carts, so it's driven on its own until it's back where it started
+// or it has been in more states than there are, which means it's going round.
+fn stays_on_track(map: &Grid<char>, cart: &Cart) -> bool {
+    let mut driving = cart.clone();
+    let states = map.width() * map.height() * 4 * 3;
+
+    for _ in 0..states {
+        move_cart(&mut driving, map);
+
+        match map.get(driving.position.x, driving.position.y) {
+            None | Some(' ') => return false,
+            _ => (),
+        }
+        if driving.position == cart.position && driving.direction == cart.direction
+            && driving.turned_towards_last == cart.turned_towards_last {
+            return true;
+        }
+    }
+
+    true
+}
+
+fn move_cart(cart: &mut Cart, map: &Grid<char>) {
+    let track = map[cart.position];
+    let horizontal = cart.direction.horizontal();
+
+    if track == '+' {
+        cart.change_direction();
+    } else if track == '/' && horizontal {
+        cart.turn_left();
+    } else if (track == '/' && !horizontal) || (track == '\\' && horizontal) {
+        cart.turn_right();
+    } else if track == '\\' && !horizontal {
+        cart.turn_left();
+    } else {
+        cart.walk();
+    }
+}
+
+fn move_carts(map: &Grid<char>, carts: &mut [Cart], crashes: &mut Vec<Point>) -> Vec<Cart> {
+    let mut positions: Vec<Point> = carts.iter().map(|c| c.position).collect();
+    let mut crashed_carts: Vec<Cart> = Vec::new();
+    let mut carts_copy = carts.to_vec();
+
+    for (i, cart) in carts.iter_mut().enumerate() {
+        move_cart(cart, map);
+        carts_copy[i].position = cart.position;
+
+        if positions.contains(&cart.position) {
+            crashes.push(cart.position);
+
+            carts_copy.iter().filter(|c| {
+                c.position == cart.position
+            }).for_each(|c| crashed_carts.push(c.clone()));
+        }
+
+        positions[i] = cart.position;
+    }
+
+    crashed_carts
+}
+
+fn sort_carts(carts: &mut [Cart]) {
+    carts.sort_by_key(|c| (c.position.y, c.position.x));
+}
+
+// Runs ticks until `done` holds after one, returning the remaining carts and all crashes so far.
+fn move_carts_until(map: &Grid<char>, done: fn(&[Cart], &[Point]) -> bool) -> (Vec<Cart>, Vec<Point>) {
+    let mut carts: Vec<Cart> = collect_carts(map);
+    let mut crashes: Vec<Point> = Vec::new();
+
+    loop {
+        let crashed_carts = move_carts(map, &mut carts, &mut crashes);
+        carts.retain(|c| !crashed_carts.contains(c));
+
+        if done(&carts, &crashes) {
+            return (carts, crashes);
+        }
+
+        sort_carts(&mut carts);
+    }
+}
+
+pub struct Day13;
+impl Solution for Day13 {
+    type Input = Grid<char>;
+    type Part1 = Point;
+    type Part2 = Maybe<Point>;
+
+    fn parse(contents: &str) -> Result<Grid<char>, ParseError> {
+        let rows: Vec<Vec<char>> = lines(contents)
+            .map(|line| match line.text.char_indices().find(|(_, c)| !" -|/\\+^v<>".contains(*c)) {
+                Some((i, c)) => Err(line.error(i, &c.to_string(), "expected a track, a cart or a space")),
+                None => Ok(line.text.chars().collect()),
+            })
+            .collect::<Result<_, _>>()?;
+        let map = Grid::from_rows(rows, ' ');
+
+        let carts = collect_carts(&map);
+        if carts.len() < 2 {
+            return Err(ParseError::at_end(contents, "expected at least two carts"));
+        }
+        if let Some(cart) = carts.iter().find(|cart| !on_track(&map, cart)) {
+            let Point { x, y } = cart.position;
+            let found = map[(x, y)].to_string();
+            return Err(ParseError::new(y as usize + 1, x as usize + 1, &found, "expected a cart on straight track"));
+        }
+        if let Some(cart) = carts.iter().find(|cart| !stays_on_track(&map, cart)) {
+            let Point { x, y } = cart.position;
+            let found = map[(x, y)].to_string();
+            return Err(ParseError::new(y as usize + 1, x as usize + 1, &found, "expected a cart that stays on the track"));
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Grid<char>) -> Point {
+        let (_, crashes) = move_carts_until(map, |_, crashes| !crashes.is_empty());
+        crashes[0]
+    }
+
+    // With an even number of carts, the last two can crash into each other.
+    fn part2(map: &Grid<char>) -> Maybe<Point> {
+        let (carts, _) = move_carts_until(map, |carts, _| carts.len() <= 1);
+        Maybe(carts.first().map(|cart| cart.position))
+    }
+}
+
+// Separate loops of track next to each other. Each loop but the last has two
+// carts driving towards each other, the last one has a single cart that
+// survives every crash.
+impl Generate for Day13 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let loops: Vec<(i32, i32)> = (0..size.clamp(2, 40))
+            .map(|_| (rng.range(3..=8), rng.range(3..=6)))
+            .collect();
+        let width = loops.iter().map(|(w, _)| w + 1).sum();
+        let height = loops.iter().map(|&(_, h)| h).max().unwrap();
+        let mut map = Grid::new(0..width, 0..height, ' ');
+        let mut left = 0;
+
+        for (i, &(w, h)) in loops.iter().enumerate() {
+            let (right, bottom) = (left + w - 1, h - 1);
+            // The straight pieces of track in clockwise order with the cart driving clockwise on them
+            let mut straights = Vec::new();
+
+            for x in left..=right {
+                for &y in &[0, bottom] {
+                    map[(x, y)] = if x == left || x == right { if (x == left) == (y == 0) { '/' } else { '\\' } } else { '-' };
+                }
+            }
+            for y in 1..bottom {
+                map[(left, y)] = '|';
+                map[(right, y)] = '|';
+                straights.push((Point::new(right, y), 'v', '^'));
+                straights.push((Point::new(left, y), '^', 'v'));
+            }
+            for x in left + 1..right {
+                straights.push((Point::new(x, 0), '>', '<'));
+                straights.push((Point::new(x, bottom), '<', '>'));
+            }
+
+            rng.shuffle(&mut straights);
+            map[straights[0].0] = straights[0].1;
+            if i + 1 < loops.len() {
+                map[straights[1].0] = straights[1].2;
+            }
+
+            left = right + 2;
+        }
+
+        map.render(|&c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day13;
+    use common::{example, examples, Solution};
+
+    const SOURCE: &str = include_str!("lib.rs");
+
+    examples! {
+        Day13,
+        first_crash: example(SOURCE, "/->-\\", 6) => part1: "7,3";
+        last_cart: example(SOURCE, "/>-<\\", 7) => part2: "6,4";
+    }
+
+    #[test]
+    fn corrupted_map() {
+        let error = Day13::parse("/->-\\\n|   |\n\\-x-/\n").unwrap_err();
+        assert_eq!(error.to_string(), "3:3: expected a track, a cart or a space, found `x`");
+
+        let error = Day13::parse("/->-\\\n|   |\n\\---/\n").unwrap_err();
+        assert_eq!(error.to_string(), "3:6: expected at least two carts");
+
+        let error = Day13::parse(">\n>\n").unwrap_err();
+        assert_eq!(error.to_string(), "1:1: expected a cart on straight track, found `>`");
+
+        let error = Day13::parse("/->-\\\n|   |\n\\-+-v\n").unwrap_err();
+        assert_eq!(error.to_string(), "3:5: expected a cart on straight track, found `v`");
+
+        let error = Day13::parse("->-\n-<-\n").unwrap_err();
+        assert_eq!(error.to_string(), "1:2: expected a cart that stays on the track, found `>`");
+    }
+
+    #[test]
+    fn no_cart_left() {
+        let map = Day13::parse("/->-<\\\n\\----/\n").unwrap();
+        assert_eq!(Day13::part1(&map).to_string(), "3,0");
+        assert_eq!(Day13::part2(&map).to_string(), "none");
+    }
+}